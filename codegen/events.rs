@@ -21,9 +21,20 @@ pub fn generate(manifest_dir: &Path, out_dir: &Path) {
     schemas.sort_by(|a, b| a.title.as_ref().cmp(&b.title.as_ref()));
 
     let output = rust::build(schemas).expect("rust types to build");
+    let mut output = output.to_string();
+
+    // Anything persisted against the generated types (e.g. the state checkpoint) is keyed by this,
+    // so it gets thrown away when the schemas change. FNV-1a rather than the std hasher, whose
+    // output can change between toolchains.
+    let hash = fnv1a(output.as_bytes());
+    output.push_str(&format!("\npub const SCHEMA_HASH: &str = \"{:016x}\";\n", hash));
 
     let output_path = out_dir.join("event.rs");
     let output_path = output_path.to_str().expect("utf8 path");
     let mut f = fs::File::create(&output_path).expect("file created");
-    f.write_all(output.to_string().as_bytes()).expect("file written");
-}
\ No newline at end of file
+    f.write_all(output.as_bytes()).expect("file written");
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
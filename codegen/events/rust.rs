@@ -10,7 +10,7 @@ pub fn build(mut schemas: Vec<SchemaObject>) -> Result<codegen::Scope> {
     let mut scope = codegen::Scope::new();
     let mut generated: HashSet<String> = HashSet::new();
     scope.import("chrono", "{DateTime, Utc}");
-    scope.import("serde", "Deserialize");
+    scope.import("serde", "{Deserialize, Deserializer, Serialize, Serializer}");
 
     // 1) Generate all structs (deduped by actual struct name), capturing each top-level schema's struct name
     let mut top_level: Vec<(String, Option<String>, String)> = Vec::new(); // (variant_name, description, struct_name)
@@ -20,19 +20,91 @@ pub fn build(mut schemas: Vec<SchemaObject>) -> Result<codegen::Scope> {
         top_level.push((title, schema.description.clone(), struct_name));
     }
 
-    // 2) Generate the Event enum after structs so variants never collapse
-    scope.raw("#[derive(Clone, Debug, Deserialize)]");
-    scope.raw("#[serde(tag = \"event\")]");
+    // 2) Generate the Event enum after structs so variants never collapse. The derived impls are
+    // only used through the hand-written ones below (remote = "Self"), which add the fallback.
+    scope.raw("#[derive(Clone, Debug, Deserialize, Serialize)]");
+    scope.raw("#[serde(tag = \"event\", remote = \"Self\")]");
     let enum_ = scope.new_enum("Event").vis("pub");
 
+    let mut variant_names = Vec::new();
     for (variant_name, description, struct_name) in top_level {
         let variant = enum_.new_variant(variant_name.as_str()).tuple(struct_name.as_str());
         if let Some(desc) = description { variant.annotation(format!("/// {}", desc)); }
+        variant_names.push(variant_name);
     }
 
+    enum_.new_variant("Unknown")
+        .named("event", "Box<str>")
+        .named("raw", "serde_json::Value")
+        .annotation("/// An event with no schema (yet), kept as it was written")
+        .annotation("#[serde(skip)]");
+
+    scope.raw(FALLBACK_IMPLS);
+
+    // 3) Every event carries a timestamp, so expose it without matching on each variant
+    let timestamp = scope
+        .new_impl("Event")
+        .new_fn("timestamp")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("DateTime<Utc>")
+        .line("match self {");
+    for variant_name in &variant_names {
+        timestamp.line(format!("    Event::{}(e) => e.timestamp,", variant_name));
+    }
+    timestamp.line("    Event::Unknown { raw, .. } => raw.get(\"timestamp\")");
+    timestamp.line("        .and_then(|t| t.as_str())");
+    timestamp.line("        .and_then(|t| t.parse().ok())");
+    timestamp.line("        .unwrap_or_default(),");
+    timestamp.line("}");
+
+    // 4) Whether an event name has a variant, so anything else falls back to `Unknown`
+    let is_known = scope
+        .new_impl("Event")
+        .new_fn("is_known")
+        .vis("pub")
+        .arg("name", "&str")
+        .ret("bool")
+        .line("matches!(name,");
+    for (i, variant_name) in variant_names.iter().enumerate() {
+        let separator = if i + 1 < variant_names.len() { " |" } else { "" };
+        is_known.line(format!("    \"{}\"{}", variant_name, separator));
+    }
+    is_known.line(")");
+
     Ok(scope)
 }
 
+/// Events whose name we don't know deserialize to `Unknown` instead of failing, and serialize back
+/// to exactly what was read. Events we do know still fail if they don't match their schema.
+const FALLBACK_IMPLS: &str = r#"impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Tag<'a> {
+            #[serde(borrow)]
+            event: Option<std::borrow::Cow<'a, str>>,
+        }
+
+        // Keep the text as read and peek at its tag, so only unknown events are built into a Value
+        let raw = Box::<serde_json::value::RawValue>::deserialize(deserializer)?;
+        let name = serde_json::from_str::<Tag>(raw.get()).ok().and_then(|tag| tag.event).unwrap_or_default();
+        if !Event::is_known(&name) {
+            let raw = serde_json::from_str(raw.get()).map_err(serde::de::Error::custom)?;
+            return Ok(Event::Unknown { event: name.into(), raw });
+        }
+        Event::deserialize(&mut serde_json::Deserializer::from_str(raw.get())).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Event {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Event::Unknown { raw, .. } => raw.serialize(serializer),
+            known => Event::serialize(known, serializer),
+        }
+    }
+}"#;
+
 fn build_struct(scope: &mut codegen::Scope, generated: &mut HashSet<String>, schema: &SchemaObject, parent_prop_name: Option<String>) -> String {
     let is_top_level = parent_prop_name.is_none();
 
@@ -61,7 +133,8 @@ fn build_struct(scope: &mut codegen::Scope, generated: &mut HashSet<String>, sch
     // Add derives for all structs so serde field attributes work on nested types too
     struct_.derive("Clone")
         .derive("Debug")
-        .derive("Deserialize");
+        .derive("Deserialize")
+        .derive("Serialize");
 
     // Add a doc comment for the struct if a description is provided
     if let Some(description) = &schema.description {
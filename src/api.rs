@@ -0,0 +1,210 @@
+//! Optional local HTTP/WebSocket server for overlays and other tools running alongside the app.
+//!
+//! - `GET /state` returns a JSON snapshot of the commander's current state
+//! - `GET /state/{section}` returns a single section of that snapshot, e.g. `/state/location`
+//! - `GET /events` upgrades to a WebSocket that pushes every journal event as it is processed
+//!
+//! The server is read-only and runs on its own thread so it never blocks the UI. The state
+//! snapshot is only built when `/state` is asked for after something changed, by the app on
+//! request, so there's no cost to it while nothing is polling.
+
+use crate::config::ApiSettings;
+use crate::journal::Event;
+use crate::state::State;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, State as Extract};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use log::{error, info, warn};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+
+/// How many events a slow WebSocket client can fall behind before it starts missing some
+const EVENT_BUFFER: usize = 256;
+
+/// How long a `/state` request waits for the app to rebuild an out of date snapshot
+const REBUILD_TIMEOUT: Duration = Duration::from_secs(2);
+
+static CHANNELS: OnceLock<Channels> = OnceLock::new();
+
+#[derive(Clone)]
+struct Channels {
+    state: watch::Sender<Value>,
+    /// The state has changed since the snapshot was last built
+    stale: Arc<AtomicBool>,
+    /// Ticked by requests that find the snapshot stale, for the app to rebuild it
+    requests: watch::Sender<()>,
+    events: broadcast::Sender<Arc<str>>,
+}
+
+impl Channels {
+    fn new() -> Self {
+        let (state, _) = watch::channel(Value::Null);
+        let (requests, _) = watch::channel(());
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
+        Channels { state, stale: Arc::new(AtomicBool::new(true)), requests, events }
+    }
+}
+
+/// Starts the server on a background thread if it is enabled in the settings.
+pub fn start(settings: &ApiSettings) {
+    if !settings.enabled {
+        return;
+    }
+
+    let channels = Channels::new();
+    if CHANNELS.set(channels.clone()).is_err() {
+        return;
+    }
+
+    let address = format!("{}:{}", settings.bind, settings.port);
+    let spawned = std::thread::Builder::new()
+        .name("api".into())
+        .spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_multi_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => return error!("Failed to start API runtime: {}", e),
+            };
+            runtime.block_on(serve(address, channels));
+        });
+
+    if let Err(e) = spawned {
+        error!("Failed to start API thread: {}", e);
+    }
+}
+
+/// Pushes a processed journal event to connected WebSocket clients.
+pub fn publish_event(event: &Event) {
+    let Some(channels) = CHANNELS.get() else { return };
+    if channels.events.receiver_count() == 0 {
+        return;
+    }
+
+    match serde_json::to_string(event) {
+        Ok(json) => { let _ = channels.events.send(json.into()); }
+        Err(e) => warn!("Failed to serialize event for the API: {}", e),
+    }
+}
+
+/// Marks the snapshot served from `/state` as out of date, to be rebuilt when it's next asked for.
+pub fn state_changed() {
+    let Some(channels) = CHANNELS.get() else { return };
+    channels.stale.store(true, Ordering::Relaxed);
+}
+
+/// Replaces the snapshot served from `/state`.
+pub fn publish_state(state: &State) {
+    let Some(channels) = CHANNELS.get() else { return };
+    channels.stale.store(false, Ordering::Relaxed);
+    channels.state.send_replace(snapshot(state));
+}
+
+/// Ticks whenever a client wants a fresh snapshot; `None` if the server isn't running.
+pub fn state_requests() -> Option<watch::Receiver<()>> {
+    CHANNELS.get().map(|channels| channels.requests.subscribe())
+}
+
+fn snapshot(state: &State) -> Value {
+    json!({
+        "commander": state.commander_name,
+        "credits": state.credits,
+        "location": state.location,
+        "ship_loadout": state.ship_loadout,
+        "materials": state.materials,
+        "missions": state.missions,
+        "nav_route": state.nav_route,
+        "system_scans": state.system_scans.get(&state.location.system_address),
+    })
+}
+
+async fn serve(address: String, channels: Channels) {
+    let app = Router::new()
+        .route("/state", get(get_state))
+        .route("/state/{section}", get(get_section))
+        .route("/events", get(get_events))
+        .with_state(channels);
+
+    let listener = match tokio::net::TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(e) => return error!("Failed to bind API to {}: {}", address, e),
+    };
+
+    info!("API listening on http://{}", address);
+    if let Err(e) = axum::serve(listener, app).await {
+        error!("API server stopped: {}", e);
+    }
+}
+
+/// The current snapshot, asking the app to rebuild it first if the state has moved on. If the app
+/// doesn't answer in time (it's still loading the history, say) the last one is served.
+async fn current_state(channels: &Channels) -> Value {
+    if channels.stale.load(Ordering::Relaxed) {
+        let mut rebuilt = channels.state.subscribe();
+        channels.requests.send_replace(());
+        let _ = tokio::time::timeout(REBUILD_TIMEOUT, rebuilt.changed()).await;
+    }
+    channels.state.borrow().clone()
+}
+
+async fn get_state(Extract(channels): Extract<Channels>) -> Json<Value> {
+    Json(current_state(&channels).await)
+}
+
+async fn get_section(Extract(channels): Extract<Channels>, Path(section): Path<String>) -> Response {
+    match current_state(&channels).await.get(&section) {
+        Some(value) => Json(value.clone()).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn get_events(Extract(channels): Extract<Channels>, upgrade: WebSocketUpgrade) -> Response {
+    let events = channels.events.subscribe();
+    upgrade.on_upgrade(move |socket| forward_events(socket, events))
+}
+
+async fn forward_events(mut socket: WebSocket, mut events: broadcast::Receiver<Arc<str>>) {
+    loop {
+        match events.recv().await {
+            Ok(json) => {
+                if socket.send(Message::Text(json.as_ref().into())).await.is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("API client fell behind, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "mock_events")))]
+mod tests {
+    use super::*;
+    use iced::futures::StreamExt;
+
+    #[tokio::test]
+    async fn requests_after_loading_get_a_fresh_snapshot() {
+        let channels = CHANNELS.get_or_init(Channels::new);
+        let mut requests = Box::pin(crate::subscription::stream_api_state_requests());
+        let mut state = State { journal_loaded: true, ..State::default() };
+
+        let line = r#"{ "timestamp":"2025-07-31T16:06:00Z", "event":"Commander", "FID":"F1", "Name":"Jameson" }"#;
+        let _ = crate::message::Message::JournalEvent(serde_json::from_str(line).unwrap()).update(&mut state);
+
+        // what the app does with the subscription's messages
+        let app = async {
+            let message = requests.next().await.unwrap();
+            assert!(matches!(message, crate::message::Message::ApiStateRequested));
+            let _ = message.update(&mut state);
+        };
+        let (snapshot, _) = tokio::join!(current_state(channels), app);
+
+        assert_eq!(snapshot["commander"], "CMDR Jameson");
+    }
+}
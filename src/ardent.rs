@@ -24,8 +24,9 @@
 
 #![allow(dead_code)]
 
+use crate::request::{RequestError, RequestPolicy};
+use crate::cache::{ResponseCache, DAY, HOUR, MINUTE};
 use std::time::Duration;
-use log::info;
 use reqwest::Url;
 use serde::Deserialize;
 
@@ -37,12 +38,15 @@ mod test;
 pub struct ArdentClient {
     http: reqwest::Client,
     base: Url,
+    cache: Option<ResponseCache>,
+    policy: RequestPolicy,
 }
 
 impl Default for ArdentClient {
     fn default() -> Self {
         Self::new("https://api.ardent-insight.com/v2/")
             .expect("Static base URL should be valid")
+            .with_cache(ResponseCache::default())
     }
 }
 
@@ -56,7 +60,19 @@ impl ArdentClient {
             .connect_timeout(Duration::from_secs(10))
             .build()?;
         let base = Url::parse(base_url)?;
-        Ok(Self { http, base })
+        Ok(Self { http, base, cache: None, policy: RequestPolicy::default() })
+    }
+
+    /// Serve responses from (and save them to) an on-disk cache.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Override the default retry, timeout and concurrency policy.
+    pub fn with_policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
     }
 
     /// GET helper that merges a relative path and query params and deserializes JSON.
@@ -73,22 +89,23 @@ impl ArdentClient {
             }
         }
 
-        info!("GET {}", url);
-        let resp = self.http.get(url.clone()).send().await?;
-        let status = resp.status();
-        let text = resp.text().await?;
-
-        if !status.is_success() {
-            info!("GET {} for {}", status, url);
-            return Err(ArdentError::Message(format!(
-                "http status {}",
-                status
-            )));
-        }
+        let text = match &self.cache {
+            Some(cache) => {
+                let client = self.clone();
+                let request = url.clone();
+                cache.get_or_fetch(url.as_str(), ttl(path), move || async move { client.fetch(request).await }).await?
+            }
+            None => self.fetch(url).await?,
+        };
+
         let data = serde_json::from_str::<T>(&text)?;
         Ok(data)
     }
 
+    async fn fetch(&self, url: Url) -> Result<String, ArdentError> {
+        Ok(self.policy.get(&self.http, &url).await?)
+    }
+
     // ========================= Version and Statistics =========================
 
     /// GET https://api.ardent-insight.com/v2/version
@@ -275,6 +292,20 @@ impl ArdentClient {
     }
 }
 
+/// How long each endpoint's responses are cached for. Market data goes out of date quickly, while
+/// system positions never change.
+fn ttl(path: &str) -> Option<Duration> {
+    if path.contains("commodit") || path.starts_with("market/") {
+        Some(MINUTE * 15)
+    } else if path.contains("/nearest/") {
+        Some(DAY)
+    } else if path.starts_with("system/") {
+        Some(DAY * 30)
+    } else {
+        Some(HOUR)
+    }
+}
+
 // ========================= Data Structures =========================
 
 #[derive(Debug, Clone, Deserialize)]
@@ -702,7 +733,7 @@ pub enum ArdentError {
     Url(#[from] url::ParseError),
     #[error("serialization error: {0}")]
     Serde(#[from] serde_json::Error),
-    #[error("ardent error: {0}")]
-    Message(String),
+    #[error(transparent)]
+    Request(#[from] RequestError),
 }
 
@@ -1,8 +1,18 @@
 use super::*;
+use crate::fixture_server;
+
+/// Recorded responses by default; the real Ardent API with `--features live_tests`.
+async fn client() -> ArdentClient {
+    if cfg!(feature = "live_tests") {
+        ArdentClient::default()
+    } else {
+        ArdentClient::new(&fixture_server::start(fixture_server::ARDENT_FIXTURES).await).unwrap()
+    }
+}
 
 #[tokio::test]
 async fn test_get_version() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let version = client.get_version().await.unwrap();
     println!("Ardent version: {:?}", version);
     assert!(!version.version.is_empty());
@@ -10,7 +20,7 @@ async fn test_get_version() {
 
 #[tokio::test]
 async fn test_get_stats() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let stats = client.get_stats().await.unwrap();
     println!("Ardent stats: {:?}", stats);
     assert!(stats.systems > 0);
@@ -22,7 +32,7 @@ async fn test_get_stats() {
 
 #[tokio::test]
 async fn test_get_station_economies_stats() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let economies = client.get_station_economies_stats().await.unwrap();
     println!("Station economies: {:?}", economies);
     assert!(!economies.primary.is_empty());
@@ -35,14 +45,14 @@ async fn test_get_station_economies_stats() {
 
 #[tokio::test]
 async fn test_get_station_types_stats() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let types = client.get_station_types_stats().await.unwrap();
     println!("Total station types: {}", types.total);
 }
 
 #[tokio::test]
 async fn test_get_commodities_report() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let commodities = client.get_commodities_report().await.unwrap();
     println!("Commodities count: {}", commodities.len());
     assert!(!commodities.is_empty());
@@ -72,7 +82,7 @@ async fn test_get_commodities_report() {
 
 #[tokio::test]
 async fn test_get_commodity_info() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let info = client.get_commodity_info("Gold").await.unwrap();
 
     println!("Gold commodity info: {:?}", info);
@@ -81,7 +91,7 @@ async fn test_get_commodity_info() {
 
 #[tokio::test]
 async fn test_get_commodity_imports() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let orders = client.get_commodity_imports("Gold", None).await.unwrap();
     println!("Gold imports count: {}", orders.len());
     if !orders.is_empty() {
@@ -93,7 +103,7 @@ async fn test_get_commodity_imports() {
 
 #[tokio::test]
 async fn test_get_commodity_exports() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let orders = client.get_commodity_exports("Gold", None).await.unwrap();
 
     println!("Gold exports count: {}", orders.len());
@@ -107,7 +117,7 @@ async fn test_get_commodity_exports() {
 
 #[tokio::test]
 async fn test_get_system_info() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let info = client.get_system_info("Sol").await.unwrap();
 
     println!("Sol system info: {:?}", info.system_address);
@@ -118,7 +128,7 @@ async fn test_get_system_info() {
 
 #[tokio::test]
 async fn test_get_nearby_systems() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let systems = client.get_nearby_systems("Sol", Some(20f32)).await.unwrap();
         println!("Nearby systems count: {}", systems.len());
         if !systems.is_empty() {
@@ -130,7 +140,7 @@ async fn test_get_nearby_systems() {
 
 #[tokio::test]
 async fn test_get_nearest_service() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let services = client.get_nearest_service("Sol", "shipyard", None).await.unwrap();
 
     println!("Nearest shipyard services count: {}", services.len());
@@ -144,7 +154,7 @@ async fn test_get_nearest_service() {
 
 #[tokio::test]
 async fn test_get_system_commodities() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let commodities = client.get_system_commodities("Sol").await.unwrap();
 
     println!("Sol commodities count: {}", commodities.len());
@@ -230,7 +240,7 @@ async fn test_invalid_base_url() {
 
 #[tokio::test]
 async fn test_get_system_info_by_address() {
-    let client = ArdentClient::default();
+    let client = client().await;
     // Sol's system address is known: 10477373803
     let info = client.get_system_info_by_address(10477373803).await.unwrap();
     
@@ -241,7 +251,7 @@ async fn test_get_system_info_by_address() {
 
 #[tokio::test]
 async fn test_get_system_commodity_imports() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let orders = client.get_system_commodity_imports("Sol", None).await.unwrap();
     
     println!("Sol commodity imports count: {}", orders.len());
@@ -254,7 +264,7 @@ async fn test_get_system_commodity_imports() {
 
 #[tokio::test]
 async fn test_get_system_commodity_exports() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let orders = client.get_system_commodity_exports("Sol", None).await.unwrap();
     
     println!("Sol commodity exports count: {}", orders.len());
@@ -267,7 +277,7 @@ async fn test_get_system_commodity_exports() {
 
 #[tokio::test]
 async fn test_get_system_commodity_data() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let data = client.get_system_commodity_data("Sol", "Gold", Some(30)).await.unwrap();
 
     println!("Sol Gold commodity data: {:?}", data);
@@ -279,7 +289,7 @@ async fn test_get_system_commodity_data() {
 
 #[tokio::test]
 async fn test_get_nearby_commodity_imports() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let params = NearbyCommodityQueryParams {
         min_volume: Some(10),
         min_price: None,
@@ -303,7 +313,7 @@ async fn test_get_nearby_commodity_imports() {
 
 #[tokio::test]
 async fn test_get_nearby_commodity_exports() {
-    let client = ArdentClient::default();
+    let client = client().await;
     let params = NearbyCommodityQueryParams {
         min_volume: Some(10),
         min_price: None,
@@ -327,7 +337,7 @@ async fn test_get_nearby_commodity_exports() {
 
 #[tokio::test]
 async fn test_get_market_commodity_data() {
-    let client = ArdentClient::default();
+    let client = client().await;
 
     // Find a market that exports Gold to get a valid market_id for the test
     let exports = client.get_commodity_exports("Gold", None).await.unwrap();
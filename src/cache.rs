@@ -0,0 +1,226 @@
+//! On-disk cache for EDSM and Ardent responses, keyed by request URL.
+//!
+//! Each endpoint has its own time to live. Within it a cached response is used as is. For the
+//! same time again afterwards the stale response is still returned straight away while a fresh
+//! copy is fetched in the background. After that the request waits for the network, but falls back
+//! to whatever is cached if the request fails, so the app keeps working offline. The cache
+//! directory is trimmed, oldest entries first, once it grows past a size cap.
+
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Directory for cached responses, in the app's data directory
+const CACHE_DIR: &str = "EliteAssist.cache";
+
+/// Default upper bound on the cache directory size, in bytes
+const MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Time-to-live helpers for the per-endpoint tables in the API clients.
+pub const MINUTE: Duration = Duration::from_secs(60);
+pub const HOUR: Duration = Duration::from_secs(60 * 60);
+pub const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    url: Box<str>,
+    fetched_at: i64,
+    body: Box<str>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Freshness {
+    Fresh,
+    Stale,
+    Expired,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(crate::config::data_dir().join(CACHE_DIR), MAX_BYTES)
+    }
+}
+
+impl ResponseCache {
+
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Self {
+        Self { dir: dir.into(), max_bytes }
+    }
+
+    /// Returns the body for `url`, from the cache when it is recent enough, otherwise by running
+    /// `fetch`. A `ttl` of `None` means the endpoint is never cached.
+    pub async fn get_or_fetch<F, Fut, E>(&self, url: &str, ttl: Option<Duration>, fetch: F) -> Result<String, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, E>> + Send + 'static,
+        E: Display + Send + 'static,
+    {
+        let Some(ttl) = ttl else { return fetch().await };
+
+        let cached = self.read(url).await;
+        match cached.as_ref().map(|entry| freshness(entry.fetched_at, Utc::now().timestamp(), ttl)) {
+            Some(Freshness::Fresh) => return Ok(cached.unwrap().body.into()),
+            Some(Freshness::Stale) => {
+                let cache = self.clone();
+                let url = url.to_string();
+                let refresh = fetch();
+                tokio::spawn(async move {
+                    match refresh.await {
+                        Ok(body) => cache.write(&url, &body).await,
+                        Err(e) => warn!("Failed to refresh cached {}: {}", url, e),
+                    }
+                });
+                return Ok(cached.unwrap().body.into());
+            }
+            Some(Freshness::Expired) | None => {}
+        }
+
+        match fetch().await {
+            Ok(body) => {
+                self.write(url, &body).await;
+                Ok(body)
+            }
+            Err(e) => match cached {
+                Some(entry) => {
+                    warn!("Using expired cache entry for {}: {}", url, e);
+                    Ok(entry.body.into())
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    async fn read(&self, url: &str) -> Option<Entry> {
+        let data = tokio::fs::read_to_string(self.path(url)).await.ok()?;
+        let entry: Entry = serde_json::from_str(&data).ok()?;
+        // the file name is only a hash, so make sure it's really the same request
+        (entry.url.as_ref() == url).then_some(entry)
+    }
+
+    async fn write(&self, url: &str, body: &str) {
+        let entry = Entry { url: url.into(), fetched_at: Utc::now().timestamp(), body: body.into() };
+        let Ok(json) = serde_json::to_string(&entry) else { return };
+
+        if let Err(e) = tokio::fs::create_dir_all(&self.dir).await {
+            return warn!("Failed to create cache directory {}: {}", self.dir.display(), e);
+        }
+        if let Err(e) = tokio::fs::write(self.path(url), json).await {
+            return warn!("Failed to write cache entry for {}: {}", url, e);
+        }
+        self.trim().await;
+    }
+
+    /// Deletes the least recently written entries until the cache fits within its size cap.
+    async fn trim(&self) {
+        let Ok(mut dir) = tokio::fs::read_dir(&self.dir).await else { return };
+
+        let mut files = Vec::new();
+        while let Ok(Some(entry)) = dir.next_entry().await {
+            if let Ok(metadata) = entry.metadata().await {
+                let modified = metadata.modified().unwrap_or(std::time::UNIX_EPOCH);
+                files.push((modified, metadata.len(), entry.path()));
+            }
+        }
+
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        if total <= self.max_bytes {
+            return;
+        }
+
+        files.sort_by_key(|(modified, _, _)| *modified);
+        for (_, len, path) in files {
+            if total <= self.max_bytes { break; }
+            if tokio::fs::remove_file(&path).await.is_ok() {
+                total -= len;
+            }
+        }
+        info!("Trimmed response cache to {} bytes", total);
+    }
+
+    /// Named by an FNV-1a hash of the URL, which unlike the std hasher stays the same between
+    /// toolchains, so the cache survives a rebuild.
+    fn path(&self, url: &str) -> PathBuf {
+        let hash = url.bytes().fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+        self.dir.join(format!("{:016x}.json", hash))
+    }
+}
+
+fn freshness(fetched_at: i64, now: i64, ttl: Duration) -> Freshness {
+    let age = now.saturating_sub(fetched_at).max(0) as u64;
+    let ttl = ttl.as_secs();
+    if age < ttl {
+        Freshness::Fresh
+    } else if age < ttl.saturating_mul(2) {
+        Freshness::Stale
+    } else {
+        Freshness::Expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str, max_bytes: u64) -> ResponseCache {
+        let dir = std::env::temp_dir().join(format!("elite-assist-cache-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        ResponseCache::new(dir, max_bytes)
+    }
+
+    #[test]
+    fn file_names_are_stable_hashes_of_the_url() {
+        let cache = ResponseCache::new("cache", MAX_BYTES);
+        assert_eq!(cache.path(""), PathBuf::from("cache/cbf29ce484222325.json"));
+        assert_eq!(cache.path("a"), PathBuf::from("cache/af63dc4c8601ec8c.json"));
+    }
+
+    #[test]
+    fn entries_go_stale_then_expire() {
+        assert_eq!(freshness(0, 59, MINUTE), Freshness::Fresh);
+        assert_eq!(freshness(0, 60, MINUTE), Freshness::Stale);
+        assert_eq!(freshness(0, 120, MINUTE), Freshness::Expired);
+    }
+
+    #[tokio::test]
+    async fn fresh_entries_skip_the_network_and_expired_ones_survive_failures() {
+        let cache = temp_cache("fallback", MAX_BYTES);
+        let url = "https://www.edsm.net/api-system-v1/bodies?systemName=Sol";
+
+        let body = cache.get_or_fetch(url, Some(HOUR), || async { Ok::<_, String>("fetched".to_string()) }).await;
+        assert_eq!(body.unwrap(), "fetched");
+
+        let body = cache.get_or_fetch(url, Some(HOUR), || async { Err::<String, _>("offline".to_string()) }).await;
+        assert_eq!(body.unwrap(), "fetched");
+
+        // a zero TTL means the entry is always expired, but it's still better than nothing offline
+        let body = cache.get_or_fetch(url, Some(Duration::ZERO), || async { Err::<String, _>("offline".to_string()) }).await;
+        assert_eq!(body.unwrap(), "fetched");
+
+        let body = cache.get_or_fetch(url, None, || async { Err::<String, _>("offline".to_string()) }).await;
+        assert!(body.is_err());
+    }
+
+    #[tokio::test]
+    async fn cache_is_trimmed_to_its_size_cap() {
+        let cache = temp_cache("trim", 200);
+        for i in 0..10 {
+            cache.write(&format!("https://example.com/{}", i), &"x".repeat(50)).await;
+        }
+
+        let total: u64 = std::fs::read_dir(&cache.dir)
+            .unwrap()
+            .flatten()
+            .map(|e| e.metadata().unwrap().len())
+            .sum();
+        assert!(total <= 200);
+    }
+}
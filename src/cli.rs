@@ -0,0 +1,444 @@
+//! Command line handling.
+//!
+//! Headless mode replays the journal history through the same state machine as the GUI, without
+//! opening a window, and prints a report of the resulting state:
+//!
+//! ```text
+//! EliteAssist report [--json] [--journal-dir <path>] [location|ranks|materials|missions|claims|exploration]...
+//! ```
+
+use crate::journal::{get_directory, replay, HistoryLoader, JournalError};
+use crate::lookup::fdev_ids::Rank;
+use crate::message::Message;
+use crate::state::State;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use thousands::Separable;
+
+const USAGE: &str = "usage: EliteAssist report [--json] [--journal-dir <path>] [location|ranks|materials|missions|claims|exploration]...";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Location,
+    Ranks,
+    Materials,
+    Missions,
+    Claims,
+    Exploration,
+}
+
+const ALL_SECTIONS: [Section; 6] = [
+    Section::Location,
+    Section::Ranks,
+    Section::Materials,
+    Section::Missions,
+    Section::Claims,
+    Section::Exploration,
+];
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    json: bool,
+    journal_dir: Option<PathBuf>,
+    sections: Vec<Section>,
+}
+
+/// Runs the `report` subcommand with the arguments following it, returning the process exit code.
+pub fn run(args: &[String]) -> i32 {
+    let options = match parse_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return 2;
+        }
+    };
+
+    let state = match load_state(options.journal_dir.clone()) {
+        Ok(state) => state,
+        Err(e) => {
+            eprintln!("Failed to read the journal: {}", e);
+            return 1;
+        }
+    };
+
+    let report = Report::new(&state, &options.sections);
+    if options.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Failed to serialize report: {}", e);
+                return 1;
+            }
+        }
+    } else {
+        print!("{}", report.to_text());
+    }
+    0
+}
+
+/// Reads the replay options for the GUI, if a recording was given.
+pub fn parse_replay(args: &[String]) -> Result<Option<replay::Settings>, String> {
+    let mut settings: Option<replay::Settings> = None;
+    let mut speed = 1.0;
+    let mut paused = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--replay" => {
+                let path = args.next().ok_or("--replay needs a journal directory or file")?;
+                settings = Some(replay::Settings { path: PathBuf::from(path), speed: 1.0, paused: false });
+            }
+            "--speed" => {
+                let factor = args.next().ok_or("--speed needs a factor")?;
+                speed = factor
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|s| *s > 0.0)
+                    .ok_or_else(|| format!("Invalid replay speed: {}", factor))?;
+            }
+            "--paused" => paused = true,
+            _ => {}
+        }
+    }
+
+    Ok(settings.map(|s| replay::Settings { speed, paused, ..s }))
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options { json: false, journal_dir: None, sections: Vec::new() };
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => options.json = true,
+            "--journal-dir" => {
+                let dir = args.next().ok_or("--journal-dir needs a path")?;
+                options.journal_dir = Some(PathBuf::from(dir));
+            }
+            "location" => options.sections.push(Section::Location),
+            "ranks" => options.sections.push(Section::Ranks),
+            "materials" => options.sections.push(Section::Materials),
+            "missions" => options.sections.push(Section::Missions),
+            "claims" => options.sections.push(Section::Claims),
+            "exploration" => options.sections.push(Section::Exploration),
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+
+    if options.sections.is_empty() {
+        options.sections = ALL_SECTIONS.to_vec();
+    }
+    Ok(options)
+}
+
+/// Feeds every message from the journal history through `Message::update`. Tasks the update
+/// returns (such as EDSM queries) are dropped, so the report only reflects the journal.
+fn load_state(journal_dir: Option<PathBuf>) -> Result<State, JournalError> {
+    let dir = match journal_dir {
+        Some(dir) => dir,
+        None => get_directory()?,
+    };
+
+    let mut state = State::default();
+    for message in HistoryLoader::with_dir(dir).messages()? {
+        match message {
+            // the GUI saves a checkpoint, loads panes and starts queries here; a report only
+            // needs to know the history is in
+            Message::JournalLoaded(position) => {
+                state.journal_loaded = true;
+                state.journal_position = position;
+            }
+            message => { let _ = message.update(&mut state); }
+        }
+    }
+    Ok(state)
+}
+
+#[derive(Serialize)]
+struct Report {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commander: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<LocationReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ranks: Option<Vec<RankReport>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    materials: Option<Vec<MaterialReport>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missions: Option<Vec<MissionReport>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    claims: Option<ClaimsReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exploration: Option<ExplorationReport>,
+}
+
+#[derive(Serialize)]
+struct LocationReport {
+    system: Box<str>,
+    system_address: u64,
+    body: Box<str>,
+    station: Option<Box<str>>,
+    docked: bool,
+}
+
+#[derive(Serialize)]
+struct RankReport {
+    category: &'static str,
+    rank: u8,
+    name: Box<str>,
+    progress: u8,
+}
+
+#[derive(Serialize)]
+struct MaterialReport {
+    category: &'static str,
+    name: Box<str>,
+    grade: u8,
+    count: u64,
+}
+
+#[derive(Serialize)]
+struct MissionReport {
+    name: Box<str>,
+    faction: Box<str>,
+    destination: Option<Box<str>>,
+    expiry: Option<DateTime<Utc>>,
+    reward: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct ClaimsReport {
+    bounties: BTreeMap<Box<str>, u32>,
+    combat_bonds: BTreeMap<Box<str>, u32>,
+}
+
+#[derive(Serialize)]
+struct ExplorationReport {
+    unsold_systems: usize,
+    unsold_bodies: usize,
+    unsold_cartographic_value: u64,
+    unsold_samples: usize,
+    unsold_exobiology_value: u64,
+}
+
+impl Report {
+
+    fn new(state: &State, sections: &[Section]) -> Self {
+        let include = |section| sections.contains(&section);
+
+        Self {
+            commander: (!state.commander_name.is_empty()).then(|| state.commander_name.clone()),
+            location: include(Section::Location).then(|| location(state)),
+            ranks: include(Section::Ranks).then(|| ranks(state)),
+            materials: include(Section::Materials).then(|| materials(state)),
+            missions: include(Section::Missions).then(|| missions(state)),
+            claims: include(Section::Claims).then(|| ClaimsReport {
+                bounties: state.bounties.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+                combat_bonds: state.combat_bonds.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            }),
+            exploration: include(Section::Exploration).then(|| ExplorationReport {
+                unsold_systems: state.exploration.systems.len(),
+                unsold_bodies: state.exploration.systems.values().map(|s| s.bodies.len()).sum(),
+                unsold_cartographic_value: state.exploration.unsold_value(),
+                unsold_samples: state.exobiology.unsold.len(),
+                unsold_exobiology_value: state.exobiology.unsold_value(),
+            }),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut out = String::new();
+
+        if let Some(commander) = &self.commander {
+            let _ = writeln!(out, "CMDR {}\n", commander);
+        }
+
+        if let Some(location) = &self.location {
+            let _ = writeln!(out, "Location");
+            let _ = writeln!(out, "  System: {}", location.system);
+            if !location.body.is_empty() {
+                let _ = writeln!(out, "  Body: {}", location.body);
+            }
+            if let Some(station) = &location.station {
+                let status = if location.docked { "docked" } else { "nearby" };
+                let _ = writeln!(out, "  Station: {} ({})", station, status);
+            }
+            out.push('\n');
+        }
+
+        if let Some(ranks) = &self.ranks {
+            let _ = writeln!(out, "Ranks");
+            for rank in ranks {
+                let _ = writeln!(out, "  {:<14} {} ({}) {}%", rank.category, rank.name, rank.rank, rank.progress);
+            }
+            out.push('\n');
+        }
+
+        if let Some(materials) = &self.materials {
+            let _ = writeln!(out, "Materials");
+            if materials.is_empty() {
+                let _ = writeln!(out, "  None");
+            }
+            for material in materials {
+                let _ = writeln!(out, "  {:<14} G{} {:<40} {}", material.category, material.grade, material.name, material.count);
+            }
+            out.push('\n');
+        }
+
+        if let Some(missions) = &self.missions {
+            let _ = writeln!(out, "Missions");
+            if missions.is_empty() {
+                let _ = writeln!(out, "  None");
+            }
+            for mission in missions {
+                let _ = writeln!(out, "  {} for {}", mission.name, mission.faction);
+                if let Some(destination) = &mission.destination {
+                    let _ = writeln!(out, "    Destination: {}", destination);
+                }
+                if let Some(expiry) = mission.expiry {
+                    let _ = writeln!(out, "    Expires: {}", expiry.format("%Y-%m-%d %H:%M"));
+                }
+                if let Some(reward) = mission.reward {
+                    let _ = writeln!(out, "    Reward: {} CR", reward.separate_with_commas());
+                }
+            }
+            out.push('\n');
+        }
+
+        if let Some(claims) = &self.claims {
+            let _ = writeln!(out, "Claims");
+            if claims.bounties.is_empty() && claims.combat_bonds.is_empty() {
+                let _ = writeln!(out, "  None");
+            }
+            for (faction, amount) in &claims.bounties {
+                let _ = writeln!(out, "  Bounty: {} {} CR", faction, amount.separate_with_commas());
+            }
+            for (faction, amount) in &claims.combat_bonds {
+                let _ = writeln!(out, "  Combat bond: {} {} CR", faction, amount.separate_with_commas());
+            }
+            out.push('\n');
+        }
+
+        if let Some(exploration) = &self.exploration {
+            let _ = writeln!(out, "Exploration");
+            let _ = writeln!(
+                out,
+                "  Unsold cartographic data: {} CR ({} bodies in {} systems)",
+                exploration.unsold_cartographic_value.separate_with_commas(),
+                exploration.unsold_bodies,
+                exploration.unsold_systems
+            );
+            let _ = writeln!(
+                out,
+                "  Unsold exobiology data: {} CR ({} samples)",
+                exploration.unsold_exobiology_value.separate_with_commas(),
+                exploration.unsold_samples
+            );
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+fn location(state: &State) -> LocationReport {
+    let location = &state.location;
+    LocationReport {
+        system: location.system_name.clone(),
+        system_address: location.system_address,
+        body: location.body_name.clone(),
+        station: location.station_name.clone(),
+        docked: location.docked,
+    }
+}
+
+fn ranks(state: &State) -> Vec<RankReport> {
+    let rank = |category, rank: u8, progress: u8, lookup: fn(&str) -> Option<&'static Rank>| RankReport {
+        category,
+        rank,
+        name: lookup(&rank.to_string()).map(|r| r.name.to_string().into()).unwrap_or_else(|| "Unknown".into()),
+        progress,
+    };
+
+    vec![
+        rank("Combat", state.rank.combat, state.progress.combat, Rank::combat),
+        rank("Trade", state.rank.trade, state.progress.trade, Rank::trading),
+        rank("Exploration", state.rank.explore, state.progress.explore, Rank::exploration),
+        rank("Mercenary", state.rank.soldier, state.progress.soldier, Rank::mercenary),
+        rank("Exobiologist", state.rank.exobiologist, state.progress.exobiologist, Rank::exobiologist),
+        rank("CQC", state.rank.cqc, state.progress.cqc, Rank::cqc),
+        rank("Federation", state.rank.federation, state.progress.federation, Rank::federation),
+        rank("Empire", state.rank.empire, state.progress.empire, Rank::empire),
+    ]
+}
+
+fn materials(state: &State) -> Vec<MaterialReport> {
+    let materials = &state.materials;
+    [("Raw", &materials.raw), ("Manufactured", &materials.manufactured), ("Encoded", &materials.encoded)]
+        .into_iter()
+        .flat_map(|(category, groups)| {
+            groups
+                .iter()
+                .flat_map(|g| &g.materials)
+                .filter(|m| m.count > 0)
+                .map(move |m| MaterialReport { category, name: m.name.clone(), grade: m.rarity, count: m.count })
+        })
+        .collect()
+}
+
+fn missions(state: &State) -> Vec<MissionReport> {
+    state.missions
+        .iter()
+        .map(|m| MissionReport {
+            name: m.name.clone(),
+            faction: m.faction.clone(),
+            destination: m.destination_system.clone(),
+            expiry: m.expiry,
+            reward: m.reward,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn all_sections_are_reported_by_default() {
+        let options = parse_args(&args(&["--json"])).unwrap();
+        assert!(options.json);
+        assert_eq!(options.sections, ALL_SECTIONS.to_vec());
+    }
+
+    #[test]
+    fn sections_and_journal_dir_can_be_chosen() {
+        let options = parse_args(&args(&["--journal-dir", "/srv/journals", "missions", "claims"])).unwrap();
+        assert_eq!(options, Options {
+            json: false,
+            journal_dir: Some(PathBuf::from("/srv/journals")),
+            sections: vec![Section::Missions, Section::Claims],
+        });
+        assert!(parse_args(&args(&["--journal-dir"])).is_err());
+        assert!(parse_args(&args(&["everything"])).is_err());
+    }
+
+    #[test]
+    fn replay_options_are_optional() {
+        assert!(parse_replay(&args(&[])).unwrap().is_none());
+
+        let settings = parse_replay(&args(&["--speed", "10", "--replay", "session.log", "--paused"])).unwrap().unwrap();
+        assert_eq!(settings.path, PathBuf::from("session.log"));
+        assert_eq!(settings.speed, 10.0);
+        assert!(settings.paused);
+
+        assert!(parse_replay(&args(&["--replay", "session.log", "--speed", "0"])).is_err());
+    }
+}
@@ -26,6 +26,56 @@ pub struct Settings {
     /// Optional user-selected journal directory; when None, OS default is used
     pub journal_dir: Option<Box<str>>,
     pub show_messages_days_limit: Option<u16>,
+    /// Optional local HTTP/WebSocket API for overlays and other tools
+    pub api: Option<ApiSettings>,
+    /// Optional uploads to the Elite Dangerous Data Network
+    pub eddn: Option<EddnSettings>,
+    /// Optional journal uploads to the commander's EDSM account
+    pub edsm: Option<EdsmSettings>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiSettings {
+    pub enabled: bool,
+    pub bind: Box<str>,
+    pub port: u16,
+}
+
+impl Default for ApiSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: "127.0.0.1".into(),
+            port: 8765,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EddnSettings {
+    pub enabled: bool,
+    /// Upload URL; point it at a local server to inspect the messages
+    pub endpoint: Box<str>,
+    /// Use EDDN's `/test` schemas, which are validated but not passed on to listeners
+    pub test_schemas: bool,
+}
+
+impl Default for EddnSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "https://eddn.edcd.io:4430/upload/".into(),
+            test_schemas: false,
+        }
+    }
+}
+
+/// Credentials for EDSM's journal API; the key is on the EDSM account's settings page.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EdsmSettings {
+    pub enabled: bool,
+    pub commander_name: Box<str>,
+    pub api_key: Box<str>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +155,10 @@ impl Settings {
                 if d.as_os_str().is_empty() { None } else { Some(d.to_string_lossy().into()) }
             });
 
+        let api = existing.as_ref().and_then(|s| s.api.clone());
+        let eddn = existing.as_ref().and_then(|s| s.eddn.clone());
+        let edsm = existing.as_ref().and_then(|s| s.edsm.clone());
+
         let settings = Settings {
             layout: layout_bc,
             visible: visible_bc,
@@ -112,6 +166,9 @@ impl Settings {
             selected_screen: selected_screen_opt,
             journal_dir,
             show_messages_days_limit: Some(layout.show_messages_days_limit),
+            api,
+            eddn,
+            edsm,
         };
         let json = serde_json::to_string_pretty(&settings).unwrap_or_else(|_| "{}".into());
         fs::write(SETTINGS_FILE, json)
@@ -134,6 +191,9 @@ impl Settings {
             selected_screen: None,
             journal_dir: None,
             show_messages_days_limit: None,
+            api: None,
+            eddn: None,
+            edsm: None,
         });
         s.journal_dir = Some(path.as_ref().to_string_lossy().into());
         let json = serde_json::to_string_pretty(&s).unwrap_or_else(|_| "{}".into());
@@ -171,6 +231,33 @@ pub fn default_journal_dir() -> std::path::PathBuf {
     std::path::PathBuf::new()
 }
 
+/// Returns the OS-specific directory for the app's own files (the state checkpoint, response
+/// cache and upload positions). Falls back to the working directory if it can't be worked out.
+pub fn data_dir() -> std::path::PathBuf {
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(app_data) = std::env::var("APPDATA") {
+            return std::path::Path::new(&app_data).join("EliteAssist");
+        }
+    }
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(home) = std::env::var("HOME") {
+            return std::path::Path::new(&home).join("Library/Application Support/EliteAssist");
+        }
+    }
+    #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+    {
+        if let Some(data_home) = std::env::var_os("XDG_DATA_HOME").filter(|d| !d.is_empty()) {
+            return Path::new(&data_home).join("EliteAssist");
+        }
+        if let Ok(home) = std::env::var("HOME") {
+            return Path::new(&home).join(".local/share/EliteAssist");
+        }
+    }
+    std::path::PathBuf::new()
+}
+
 pub fn to_configuration(node: &LayoutNode) -> pane_grid::Configuration<Box<dyn pane::Type>> {
     match node {
         LayoutNode::Pane(id) => pane_grid::Configuration::Pane(pane::from_title(id.as_ref())),
@@ -0,0 +1,151 @@
+//! Opt-in uploads to the Elite Dangerous Data Network (https://eddn.edcd.io), so the markets,
+//! outfitting, shipyards, routes and scans we see help keep third-party tools up to date.
+//!
+//! Only events seen live are sent, never ones read back from the journal history or a replay.
+//! Messages are queued and sent one at a time from a background thread. Failed uploads that
+//! could still succeed are retried, and anything EDDN rejects is logged and dropped.
+
+pub mod message;
+
+use crate::config::EddnSettings;
+use crate::journal::game::Game;
+use crate::journal::{replay, Event};
+use crate::request::RequestPolicy;
+use crate::state::State;
+use chrono::{Duration, Utc};
+use log::{debug, error, info, warn};
+use message::System;
+use reqwest::Url;
+use serde_json::Value;
+use std::sync::{Mutex, OnceLock};
+use tokio::sync::mpsc;
+
+/// Messages waiting to be sent; more than this and new ones are dropped
+const QUEUE_SIZE: usize = 100;
+
+/// Snapshot files can be left over from an earlier session, so anything older isn't sent
+const MAX_AGE: Duration = Duration::minutes(15);
+
+/// How long to wait after an upload has failed even with retries, and how often to try again
+const RETRY_LATER: std::time::Duration = std::time::Duration::from_secs(60);
+const RETRY_LATER_ATTEMPTS: u32 = 5;
+
+static UPLOADER: OnceLock<Uploader> = OnceLock::new();
+
+struct Uploader {
+    test_schemas: bool,
+    game: Mutex<Game>,
+    queue: mpsc::Sender<Value>,
+}
+
+/// Starts the uploader on a background thread if it is enabled in the settings.
+pub fn start(settings: &EddnSettings) {
+    if !settings.enabled || replay::is_active() {
+        return;
+    }
+
+    let url = match Url::parse(&settings.endpoint) {
+        Ok(url) => url,
+        Err(e) => return error!("Invalid EDDN endpoint {}: {}", settings.endpoint, e),
+    };
+
+    let (queue, receiver) = mpsc::channel(QUEUE_SIZE);
+    let uploader = Uploader {
+        test_schemas: settings.test_schemas,
+        game: Mutex::new(Game::from_latest_journal()),
+        queue,
+    };
+    if UPLOADER.set(uploader).is_err() {
+        return;
+    }
+
+    let spawned = std::thread::Builder::new()
+        .name("eddn".into())
+        .spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => return error!("Failed to start EDDN runtime: {}", e),
+            };
+            runtime.block_on(upload(url, receiver));
+        });
+
+    if let Err(e) = spawned {
+        error!("Failed to start EDDN thread: {}", e);
+    }
+}
+
+/// Keeps track of the game version, and queues a message for any live event EDDN collects.
+/// Called before the event is applied, so the state still describes where it happened.
+pub fn publish(event: &Event, state: &State) {
+    let Some(uploader) = UPLOADER.get() else { return };
+
+    let is_startup = matches!(event, Event::Fileheader(_) | Event::LoadGame(_));
+    let is_collected = matches!(event,
+        Event::FSDJump(_) | Event::Location(_) | Event::CarrierJump(_) | Event::Docked(_)
+        | Event::Scan(_) | Event::SAASignalsFound(_) | Event::FSSDiscoveryScan(_)
+        | Event::NavBeaconScan(_) | Event::NavRoute(_) | Event::Market(_)
+        | Event::Outfitting(_) | Event::Shipyard(_));
+    if !is_startup && (!is_collected || !state.journal_loaded) {
+        return;
+    }
+
+    let value = match serde_json::to_value(event) {
+        Ok(value) => value,
+        Err(e) => return warn!("Failed to serialize event for EDDN: {}", e),
+    };
+
+    if is_startup {
+        return uploader.game.lock().unwrap().observe(&value);
+    }
+    if Utc::now() - event.timestamp() > MAX_AGE {
+        return;
+    }
+
+    let game = uploader.game.lock().unwrap().clone();
+    if game.version.is_empty() {
+        return debug!("Not uploading to EDDN until the game version is known");
+    }
+
+    let system = System {
+        name: &state.location.system_name,
+        address: state.location.system_address,
+        star_pos: &state.location.star_pos,
+    };
+    let Some((schema, message)) = message::build(value, &system, &game) else { return };
+
+    let envelope = message::envelope(schema, uploader.test_schemas, &state.commander_name, &game, message);
+    if uploader.queue.try_send(envelope).is_err() {
+        warn!("EDDN upload queue is full, dropping {:?} message", schema);
+    }
+}
+
+async fn upload(url: Url, mut queue: mpsc::Receiver<Value>) {
+    let http = match reqwest::Client::builder().user_agent("EliteAssist-EDDN-Uploader/0.1").build() {
+        Ok(http) => http,
+        Err(e) => return error!("Failed to create EDDN client: {}", e),
+    };
+    // one upload at a time, so messages arrive in the order they happened
+    let policy = RequestPolicy { max_per_host: 1, ..Default::default() };
+
+    info!("Uploading to EDDN at {}", url);
+    while let Some(envelope) = queue.recv().await {
+        let schema = envelope["$schemaRef"].as_str().unwrap_or_default().to_string();
+
+        for attempt in 1..=RETRY_LATER_ATTEMPTS {
+            match policy.post_json(&http, &url, &envelope).await {
+                Ok(_) => {
+                    debug!("Uploaded {} to EDDN", schema);
+                    break;
+                }
+                Err(e) if e.is_transient() && attempt < RETRY_LATER_ATTEMPTS => {
+                    warn!("EDDN upload failed, trying again in {}s: {}", RETRY_LATER.as_secs(), e);
+                    tokio::time::sleep(RETRY_LATER).await;
+                }
+                Err(e) => {
+                    warn!("EDDN didn't accept {}: {}", schema, e);
+                    break;
+                }
+            }
+        }
+    }
+}
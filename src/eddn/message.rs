@@ -0,0 +1,335 @@
+//! Builds EDDN messages from journal events.
+//!
+//! Events are handled as the JSON the game wrote (which is what `Event` serializes back to), so the
+//! messages keep the journal's own key names. Localised strings and anything personal to the
+//! commander are stripped, and events that don't say which system they happened in get the
+//! current system added, as the EDDN schemas require.
+
+use crate::journal::game::Game;
+use serde_json::{json, Map, Value};
+
+const SOFTWARE_NAME: &str = "EliteAssist";
+
+/// Top-level keys that are personal to the commander, and not wanted in journal messages
+const PERSONAL_KEYS: &[&str] = &[
+    "ActiveFine", "CockpitBreach", "BoostUsed", "FuelLevel", "FuelUsed", "JumpDist", "Latitude",
+    "Longitude", "Wanted",
+];
+
+/// Keys in each of the `Factions` that are about the commander rather than the faction
+const PERSONAL_FACTION_KEYS: &[&str] = &["HappiestSystem", "HomeSystem", "MyReputation", "SquadronFaction"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Schema {
+    Journal,
+    Commodity,
+    Outfitting,
+    Shipyard,
+    FssDiscoveryScan,
+    NavBeaconScan,
+    NavRoute,
+}
+
+impl Schema {
+    fn path(&self) -> &'static str {
+        match self {
+            Schema::Journal => "journal/1",
+            Schema::Commodity => "commodity/3",
+            Schema::Outfitting => "outfitting/2",
+            Schema::Shipyard => "shipyard/2",
+            Schema::FssDiscoveryScan => "fssdiscoveryscan/1",
+            Schema::NavBeaconScan => "navbeaconscan/1",
+            Schema::NavRoute => "navroute/1",
+        }
+    }
+
+    pub fn schema_ref(&self, test: bool) -> String {
+        format!("https://eddn.edcd.io/schemas/{}{}", self.path(), if test { "/test" } else { "" })
+    }
+}
+
+/// The system the commander is in, for events that only give its address.
+pub struct System<'a> {
+    pub name: &'a str,
+    pub address: u64,
+    pub star_pos: &'a [f64],
+}
+
+/// Builds the message for a journal event, if it's one EDDN collects and we have everything the
+/// schema needs.
+pub fn build(event: Value, system: &System, game: &Game) -> Option<(Schema, Value)> {
+    let Value::Object(mut event) = strip(event) else { return None };
+    let name = event.get("event")?.as_str()?.to_string();
+
+    let (schema, mut message) = match name.as_str() {
+        "FSDJump" | "Location" | "CarrierJump" => (Schema::Journal, journal(event)?),
+        "Docked" | "Scan" | "SAASignalsFound" => (Schema::Journal, journal(in_system(event, system)?)?),
+        "FSSDiscoveryScan" => {
+            event.remove("Progress");
+            (Schema::FssDiscoveryScan, in_system(event, system)?)
+        }
+        "NavBeaconScan" => (Schema::NavBeaconScan, in_system(event, system)?),
+        "NavRoute" => (Schema::NavRoute, nav_route(event)?),
+        "Market" => (Schema::Commodity, commodity(&event)?),
+        "Outfitting" => (Schema::Outfitting, outfitting(&event)?),
+        "Shipyard" => (Schema::Shipyard, shipyard(&event)?),
+        _ => return None,
+    };
+
+    message.insert("horizons".into(), game.horizons.into());
+    message.insert("odyssey".into(), game.odyssey.into());
+    Some((schema, Value::Object(message)))
+}
+
+/// Wraps a message for upload.
+pub fn envelope(schema: Schema, test: bool, uploader: &str, game: &Game, message: Value) -> Value {
+    json!({
+        "$schemaRef": schema.schema_ref(test),
+        "header": {
+            "uploaderID": uploader,
+            "softwareName": SOFTWARE_NAME,
+            "softwareVersion": env!("CARGO_PKG_VERSION"),
+            "gameversion": game.version,
+            "gamebuild": game.build,
+        },
+        "message": message,
+    })
+}
+
+/// Drops localised strings and empty optional fields, all the way down.
+fn strip(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(map.into_iter()
+            .filter(|(key, value)| !key.ends_with("_Localised") && !value.is_null())
+            .map(|(key, value)| (key, strip(value)))
+            .collect()),
+        Value::Array(items) => Value::Array(items.into_iter().map(strip).collect()),
+        other => other,
+    }
+}
+
+/// Adds the current system's name and position to an event that happened there, or gives up if
+/// it's about somewhere else (e.g. we've jumped since).
+fn in_system(mut event: Map<String, Value>, system: &System) -> Option<Map<String, Value>> {
+    if event.get("SystemAddress")?.as_u64()? != system.address || system.star_pos.len() != 3 {
+        return None;
+    }
+    if let Some(name) = event.get("StarSystem").and_then(Value::as_str) && name != system.name {
+        return None;
+    }
+    if !event.contains_key("StarSystem") && !event.contains_key("SystemName") {
+        event.insert("StarSystem".into(), system.name.into());
+    }
+    event.insert("StarPos".into(), system.star_pos.into());
+    Some(event)
+}
+
+fn journal(mut event: Map<String, Value>) -> Option<Map<String, Value>> {
+    for key in PERSONAL_KEYS {
+        event.remove(*key);
+    }
+    if let Some(Value::Array(factions)) = event.get_mut("Factions") {
+        for faction in factions.iter_mut().filter_map(Value::as_object_mut) {
+            for key in PERSONAL_FACTION_KEYS {
+                faction.remove(*key);
+            }
+        }
+    }
+
+    let has_position = event.get("StarPos").and_then(Value::as_array).is_some_and(|pos| pos.len() == 3);
+    if !has_position || !event.contains_key("StarSystem") || !event.contains_key("SystemAddress") {
+        return None;
+    }
+    Some(event)
+}
+
+fn nav_route(event: Map<String, Value>) -> Option<Map<String, Value>> {
+    // the journal's own NavRoute event is empty; only NavRoute.json lists the route
+    if event.get("Route")?.as_array()?.is_empty() {
+        return None;
+    }
+    Some(event)
+}
+
+/// The fields every station message starts with.
+fn station(event: &Map<String, Value>) -> Option<Map<String, Value>> {
+    let mut message = Map::new();
+    message.insert("systemName".into(), event.get("StarSystem")?.clone());
+    message.insert("stationName".into(), event.get("StationName")?.clone());
+    message.insert("marketId".into(), event.get("MarketID")?.clone());
+    message.insert("timestamp".into(), event.get("timestamp")?.clone());
+    Some(message)
+}
+
+fn commodity(event: &Map<String, Value>) -> Option<Map<String, Value>> {
+    let commodities: Vec<Value> = event.get("Items")?
+        .as_array()?
+        .iter()
+        .filter(|item| item.get("Category").and_then(Value::as_str) != Some("$MARKET_category_nonmarketable;"))
+        .filter_map(|item| Some(json!({
+            "name": commodity_name(item.get("Name")?.as_str()?),
+            "meanPrice": item.get("MeanPrice")?,
+            "buyPrice": item.get("BuyPrice")?,
+            "stock": item.get("Stock")?,
+            "stockBracket": item.get("StockBracket")?,
+            "sellPrice": item.get("SellPrice")?,
+            "demand": item.get("Demand")?,
+            "demandBracket": item.get("DemandBracket")?,
+        })))
+        .collect();
+    if commodities.is_empty() {
+        return None;
+    }
+
+    let mut message = station(event)?;
+    if let Some(station_type) = event.get("StationType") {
+        message.insert("stationType".into(), station_type.clone());
+    }
+    message.insert("commodities".into(), commodities.into());
+    Some(message)
+}
+
+/// `$platinum_name;` -> `platinum`
+fn commodity_name(name: &str) -> String {
+    let name = name.trim_start_matches('$').trim_end_matches(';').to_lowercase();
+    name.strip_suffix("_name").map(str::to_string).unwrap_or(name)
+}
+
+fn outfitting(event: &Map<String, Value>) -> Option<Map<String, Value>> {
+    let modules: Vec<Value> = event.get("Items")?
+        .as_array()?
+        .iter()
+        .filter_map(|item| item.get("Name")?.as_str())
+        .filter(|name| {
+            let name = name.to_lowercase();
+            (name.starts_with("hpt_") || name.starts_with("int_") || name.contains("_armour_"))
+                && name != "int_planetapproachsuite"
+        })
+        .map(Value::from)
+        .collect();
+    if modules.is_empty() {
+        return None;
+    }
+
+    let mut message = station(event)?;
+    message.insert("modules".into(), modules.into());
+    Some(message)
+}
+
+fn shipyard(event: &Map<String, Value>) -> Option<Map<String, Value>> {
+    let ships: Vec<Value> = event.get("PriceList")?
+        .as_array()?
+        .iter()
+        .filter_map(|ship| ship.get("ShipType").cloned())
+        .collect();
+
+    let mut message = station(event)?;
+    message.insert("ships".into(), ships.into());
+    if let Some(allowed) = event.get("AllowCobraMkIV") {
+        message.insert("allowCobraMkIV".into(), allowed.clone());
+    }
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOL: System = System { name: "Sol", address: 10477373803, star_pos: &[0.0, 0.0, 0.0] };
+
+    fn example(path: &str, event: &str) -> Value {
+        let data = std::fs::read_to_string(format!("src/example_data/{}", path)).unwrap();
+        let events: Vec<Value> = serde_json::from_str(&data).unwrap();
+        events.into_iter()
+            .find(|e| e["event"] == event && e.as_object().unwrap().len() > 5)
+            .unwrap()
+    }
+
+    fn game() -> Game {
+        Game { version: "4.1.3.0".into(), build: "r316268/r0 ".into(), horizons: true, odyssey: true }
+    }
+
+    #[test]
+    fn journal_messages_drop_personal_and_localised_fields() {
+        let event = json!({
+            "timestamp": "3311-01-01T12:00:00Z", "event": "FSDJump", "StarSystem": "Sol",
+            "SystemAddress": 10477373803u64, "StarPos": [0.0, 0.0, 0.0], "JumpDist": 8.5, "FuelUsed": 1.2,
+            "FuelLevel": 30.0, "BoostUsed": null, "SystemEconomy_Localised": "Refinery",
+            "Factions": [{ "Name": "Mother Gaia", "MyReputation": 12.5, "HomeSystem": true }],
+        });
+
+        let (schema, message) = build(event, &SOL, &game()).unwrap();
+        assert_eq!(schema, Schema::Journal);
+        assert_eq!(message, json!({
+            "timestamp": "3311-01-01T12:00:00Z", "event": "FSDJump", "StarSystem": "Sol",
+            "SystemAddress": 10477373803u64, "StarPos": [0.0, 0.0, 0.0],
+            "Factions": [{ "Name": "Mother Gaia" }], "horizons": true, "odyssey": true,
+        }));
+    }
+
+    #[test]
+    fn events_elsewhere_are_not_sent() {
+        let scan = json!({
+            "timestamp": "3311-01-01T12:00:00Z", "event": "Scan", "BodyName": "Procyon B 3 a",
+            "StarSystem": "Procyon", "SystemAddress": 4356789,
+        });
+        assert!(build(scan, &SOL, &game()).is_none());
+
+        let signals = json!({
+            "timestamp": "3311-01-01T12:00:00Z", "event": "SAASignalsFound", "BodyName": "Earth",
+            "SystemAddress": 10477373803u64, "Signals": [],
+        });
+        let (_, message) = build(signals, &SOL, &game()).unwrap();
+        assert_eq!(message["StarSystem"], "Sol");
+        assert_eq!(message["StarPos"], json!([0.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn discovery_scans_lose_their_progress() {
+        let (schema, message) = build(example("exploration/fss_discovery_scan.json", "FSSDiscoveryScan"), &SOL, &game()).unwrap();
+
+        assert_eq!(schema, Schema::FssDiscoveryScan);
+        assert!(message.get("Progress").is_none());
+        assert!(message.get("StarSystem").is_none());
+        assert_eq!(message["SystemName"], "Sol");
+    }
+
+    #[test]
+    fn market_becomes_a_commodity_message() {
+        let (schema, message) = build(example("station_services/market.json", "Market"), &SOL, &game()).unwrap();
+
+        assert_eq!(schema, Schema::Commodity);
+        assert_eq!(message["systemName"], "Polevnic");
+        assert_eq!(message["marketId"], 3221497856u64);
+        let platinum = &message["commodities"][0];
+        assert_eq!(platinum["name"], "platinum");
+        assert_eq!(platinum["meanPrice"], 55505);
+        assert!(platinum.get("Name_Localised").is_none());
+    }
+
+    #[test]
+    fn outfitting_keeps_only_modules_eddn_accepts() {
+        let (schema, message) = build(example("station_services/outfitting.json", "Outfitting"), &SOL, &game()).unwrap();
+
+        assert_eq!(schema, Schema::Outfitting);
+        let modules = message["modules"].as_array().unwrap();
+        assert!(modules.contains(&json!("hpt_pulselaser_fixed_medium")));
+        assert!(modules.iter().all(|m| m != "int_planetapproachsuite"));
+    }
+
+    #[test]
+    fn envelopes_point_at_test_schemas_when_asked() {
+        let envelope = envelope(Schema::NavRoute, true, "Jameson", &game(), json!({}));
+
+        assert_eq!(envelope["$schemaRef"], "https://eddn.edcd.io/schemas/navroute/1/test");
+        assert_eq!(envelope["header"]["gameversion"], "4.1.3.0");
+        assert_eq!(envelope["header"]["softwareName"], SOFTWARE_NAME);
+    }
+
+    #[test]
+    fn commodity_names_are_symbolic() {
+        assert_eq!(commodity_name("$platinum_name;"), "platinum");
+        assert_eq!(commodity_name("$Gold_Name;"), "gold");
+        assert_eq!(commodity_name("drones"), "drones");
+    }
+}
@@ -4,6 +4,7 @@
 //! - https://www.edsm.net/en/api-status-v1
 //! - https://www.edsm.net/en/api-system-v1
 //! - https://www.edsm.net/en/api-v1
+//! - https://www.edsm.net/en/api-journal-v1
 //!
 //! It uses reqwest (async) and serde for JSON, and is safe to call from the GUI without
 //! blocking the UI thread. Construct once and reuse to benefit from connection pooling.
@@ -30,6 +31,7 @@ pub mod bodies;
 pub mod deaths;
 pub mod traffic;
 pub mod system;
+pub mod journal;
 mod server_status;
 
 use stations::*;
@@ -38,10 +40,12 @@ use bodies::*;
 use traffic::*;
 use deaths::*;
 use system::*;
+use journal::*;
 pub use server_status::*;
 
+use crate::request::{RequestError, RequestPolicy};
+use crate::cache::{ResponseCache, DAY, HOUR};
 use std::time::Duration;
-use log::info;
 use reqwest::Url;
 use serde::Deserialize;
 
@@ -50,12 +54,15 @@ use serde::Deserialize;
 pub struct EdsmClient {
     http: reqwest::Client,
     base: Url,
+    cache: Option<ResponseCache>,
+    policy: RequestPolicy,
 }
 
 impl Default for EdsmClient {
     fn default() -> Self {
         Self::new("https://www.edsm.net/")
             .expect("Static base URL should be valid")
+            .with_cache(ResponseCache::default())
     }
 }
 
@@ -69,7 +76,19 @@ impl EdsmClient {
             .connect_timeout(Duration::from_secs(10))
             .build()?;
         let base = Url::parse(base_url)?;
-        Ok(Self { http, base })
+        Ok(Self { http, base, cache: None, policy: RequestPolicy::default() })
+    }
+
+    /// Serve responses from (and save them to) an on-disk cache.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Override the default retry, timeout and concurrency policy.
+    pub fn with_policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
     }
 
     /// GET helper that merges a relative path and query params, and deserializes JSON.
@@ -86,22 +105,23 @@ impl EdsmClient {
             }
         }
 
-        info!("GET {}", url);
-        let resp = self.http.get(url.clone()).send().await?;
-        let status = resp.status();
-        let text = resp.text().await?;
-
-        if !status.is_success() {
-            info!("GET {} for {}", status, url);
-            return Err(EdsmError::Message(format!(
-                "http status {}",
-                status
-            )));
-        }
+        let text = match &self.cache {
+            Some(cache) => {
+                let client = self.clone();
+                let request = url.clone();
+                cache.get_or_fetch(url.as_str(), ttl(path), move || async move { client.fetch(request).await }).await?
+            }
+            None => self.fetch(url).await?,
+        };
+
         let data = serde_json::from_str::<T>(&text)?;
         Ok(data)
     }
 
+    async fn fetch(&self, url: Url) -> Result<String, EdsmError> {
+        Ok(self.policy.get(&self.http, &url).await?)
+    }
+
     // ========================= api-status-v1 =========================
 
     /// GET https://www.edsm.net/api-status-v1/elite-server
@@ -164,6 +184,33 @@ impl EdsmClient {
 
         self.get_json("api-v1/sphere-systems", &q).await
     }
+
+    // ========================= api-journal-v1 =========================
+
+    /// GET https://www.edsm.net/api-journal-v1/discard
+    /// The journal events EDSM doesn't want; they must be left out of uploads.
+    pub async fn get_journal_discard(&self) -> Result<Vec<Box<str>>, EdsmError> {
+        self.get_json("api-journal-v1/discard", &[]).await
+    }
+
+    /// POST https://www.edsm.net/api-journal-v1
+    /// Uploads a batch of journal events to the commander's EDSM account. Never cached.
+    pub async fn post_journal(&self, upload: &JournalUpload) -> Result<JournalResponse, EdsmError> {
+        let url = self.base.join("api-journal-v1")?;
+        let message = serde_json::to_string(&upload.events)?;
+        let fields = [
+            ("commanderName", upload.commander_name.as_ref()),
+            ("apiKey", upload.api_key.as_ref()),
+            ("fromSoftware", "EliteAssist"),
+            ("fromSoftwareVersion", env!("CARGO_PKG_VERSION")),
+            ("fromGameVersion", upload.game_version.as_ref()),
+            ("fromGameBuild", upload.game_build.as_ref()),
+            ("message", message.as_str()),
+        ];
+
+        let text = self.policy.post_form(&self.http, &url, &fields).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +245,19 @@ impl EdsmClient {
     }
 }
 
+/// How long each endpoint's responses are cached for. The server status is polled for its live
+/// value, so it isn't cached at all.
+fn ttl(path: &str) -> Option<Duration> {
+    match path {
+        "api-v1/system" | "api-v1/sphere-systems" => Some(DAY * 7),
+        "api-system-v1/bodies" | "api-system-v1/stations" => Some(DAY),
+        "api-system-v1/factions" => Some(HOUR * 6),
+        "api-system-v1/traffic" | "api-system-v1/deaths" => Some(HOUR),
+        "api-journal-v1/discard" => Some(DAY),
+        _ => None,
+    }
+}
+
 /// Shared counts object for traffic/deaths
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct Counts {
@@ -222,6 +282,6 @@ pub enum EdsmError {
     Url(#[from] url::ParseError),
     #[error("serialization error: {0}")]
     Serde(#[from] serde_json::Error),
-    #[error("edsm error: {0}")]
-    Message(String),
+    #[error(transparent)]
+    Request(#[from] RequestError),
 }
\ No newline at end of file
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A batch of journal events for https://www.edsm.net/en/api-journal-v1, sent as they appear in
+/// the journal plus the transient `_systemAddress`-style fields EDSM asks for.
+#[derive(Clone, Debug)]
+pub struct JournalUpload {
+    pub commander_name: Box<str>,
+    pub api_key: Box<str>,
+    pub game_version: Box<str>,
+    pub game_build: Box<str>,
+    pub events: Vec<Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct JournalResponse {
+    pub msgnum: u16,
+    pub msg: Box<str>,
+    /// One result per uploaded event, in the order they were sent
+    pub events: Option<Vec<JournalEventResult>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct JournalEventResult {
+    pub msgnum: u16,
+    pub msg: Box<str>,
+}
+
+impl JournalResponse {
+    /// EDSM answers 1xx when the batch was accepted; anything else means it was refused, for
+    /// example 203 for an unknown commander or an invalid API key.
+    pub fn is_ok(&self) -> bool {
+        (100..200).contains(&self.msgnum)
+    }
+}
+
+impl JournalEventResult {
+    pub fn is_ok(&self) -> bool {
+        (100..200).contains(&self.msgnum)
+    }
+}
@@ -0,0 +1,412 @@
+//! Opt-in uploads of the journal to the commander's own EDSM account
+//! (https://www.edsm.net/en/api-journal-v1), so their flight log, ranks and discoveries show up
+//! there without running a separate uploader.
+//!
+//! Events are uploaded in batches from a background thread, leaving out the ones EDSM lists as
+//! discarded. The position of the last uploaded event is saved to disk, so the history read back
+//! at startup only sends what was played since, and nothing is sent twice. On the very first run
+//! the position starts at the current time rather than uploading the whole history.
+
+use crate::config::EdsmSettings;
+use crate::edsm::journal::JournalUpload;
+use crate::edsm::{EdsmClient, EdsmError};
+use crate::journal::game::Game;
+use crate::journal::{replay, Event};
+use crate::state::State;
+use chrono::{DateTime, Utc};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+use std::fs;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+/// File name for the position of the last uploaded event, in the app's data directory
+const CURSOR_FILE: &str = "EliteAssist.edsm.json";
+
+/// Most events sent in one request, and how long to wait for more before sending a smaller batch
+const BATCH_SIZE: usize = 100;
+const BATCH_DELAY: Duration = Duration::from_secs(5);
+
+/// How long to wait after an upload has failed even with retries before trying again
+const RETRY_LATER: Duration = Duration::from_secs(60);
+
+static UPLOADER: OnceLock<Uploader> = OnceLock::new();
+
+struct Uploader {
+    commander_name: Box<str>,
+    filter: Mutex<Filter>,
+    queue: mpsc::UnboundedSender<Pending>,
+    status: watch::Sender<Status>,
+}
+
+/// How the upload is getting on, shown on the settings screen.
+#[derive(Debug, Clone, Default)]
+pub struct Status {
+    /// Events waiting to be sent
+    pub pending: usize,
+    /// Events sent this session
+    pub uploaded: u64,
+    pub last_upload: Option<DateTime<Utc>>,
+    pub last_error: Option<Box<str>>,
+    /// EDSM refused the uploads outright (e.g. a wrong API key), so nothing more is sent
+    pub stopped: bool,
+}
+
+/// Where an event sits in the journal: its timestamp, and how many events before it (plus one)
+/// share that timestamp, since the journal only has whole seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Cursor {
+    timestamp: DateTime<Utc>,
+    count: u32,
+}
+
+impl Cursor {
+    fn load() -> Option<Cursor> {
+        let json = fs::read_to_string(crate::config::data_dir().join(CURSOR_FILE)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    fn save(&self) {
+        let dir = crate::config::data_dir();
+        let result = serde_json::to_string(self)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                if !dir.as_os_str().is_empty() {
+                    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+                }
+                fs::write(dir.join(CURSOR_FILE), json).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            warn!("Failed to save EDSM upload position: {}", e);
+        }
+    }
+}
+
+/// Decides which events are new, and remembers what EDSM wants alongside them.
+#[derive(Default)]
+struct Filter {
+    uploaded_to: Option<Cursor>,
+    last: Option<Cursor>,
+    game: Game,
+    commander: Option<Box<str>>,
+    market_id: Option<Value>,
+}
+
+struct Pending {
+    position: Cursor,
+    game: Game,
+    event: Value,
+}
+
+/// Starts the uploader on a background thread if it is enabled in the settings.
+pub fn start(settings: &EdsmSettings) {
+    if !settings.enabled || replay::is_active() {
+        return;
+    }
+    if settings.commander_name.is_empty() || settings.api_key.is_empty() {
+        return warn!("EDSM journal upload needs a commander name and API key");
+    }
+
+    let uploaded_to = Cursor::load().unwrap_or_else(|| {
+        let cursor = Cursor { timestamp: Utc::now(), count: 0 };
+        cursor.save();
+        cursor
+    });
+
+    let (queue, receiver) = mpsc::unbounded_channel();
+    let (status, _) = watch::channel(Status::default());
+    let uploader = Uploader {
+        commander_name: settings.commander_name.clone(),
+        filter: Mutex::new(Filter {
+            uploaded_to: Some(uploaded_to),
+            game: Game::from_latest_journal(),
+            ..Default::default()
+        }),
+        queue,
+        status,
+    };
+    if UPLOADER.set(uploader).is_err() {
+        return;
+    }
+
+    let settings = settings.clone();
+    let spawned = std::thread::Builder::new()
+        .name("edsm-upload".into())
+        .spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => return error!("Failed to start EDSM upload runtime: {}", e),
+            };
+            runtime.block_on(upload(settings, receiver));
+        });
+
+    if let Err(e) = spawned {
+        error!("Failed to start EDSM upload thread: {}", e);
+    }
+}
+
+/// Watches the upload status, if the uploader is running.
+pub fn status() -> Option<watch::Receiver<Status>> {
+    UPLOADER.get().map(|uploader| uploader.status.subscribe())
+}
+
+/// Queues any event newer than the last one uploaded, history included. Called before the event
+/// is applied, so the state still describes where it happened.
+pub fn publish(event: &Event, state: &State) {
+    let Some(uploader) = UPLOADER.get() else { return };
+    if uploader.status.borrow().stopped {
+        return;
+    }
+
+    let mut filter = uploader.filter.lock().unwrap();
+    let position = filter.position(event.timestamp());
+    let is_startup = matches!(event, Event::Fileheader(_) | Event::LoadGame(_) | Event::Commander(_));
+    let is_new = filter.uploaded_to.is_none_or(|uploaded_to| position > uploaded_to);
+    if !is_new && !is_startup {
+        return;
+    }
+
+    let value = match serde_json::to_value(event) {
+        Ok(value) => drop_nulls(value),
+        Err(e) => return warn!("Failed to serialize event for EDSM: {}", e),
+    };
+
+    if is_startup {
+        filter.game.observe(&value);
+        if let Some(name) = value.get("Commander").or_else(|| value.get("Name")).and_then(Value::as_str) {
+            filter.commander = Some(name.into());
+        }
+    }
+    if !is_new {
+        return;
+    }
+
+    let commander = filter.commander.as_deref().unwrap_or(&state.commander_name);
+    if !commander.is_empty() && !commander.eq_ignore_ascii_case(&uploader.commander_name) {
+        return debug!("Not uploading events for CMDR {} to EDSM", commander);
+    }
+
+    let value = with_transient_fields(value, state, filter.market_id.as_ref());
+    match event {
+        Event::Docked(_) | Event::Location(_) => filter.market_id = value.get("MarketID").cloned(),
+        Event::Undocked(_) | Event::FSDJump(_) | Event::CarrierJump(_) => filter.market_id = None,
+        _ => {}
+    }
+
+    let pending = Pending { position, game: filter.game.clone(), event: value };
+    if uploader.queue.send(pending).is_ok() {
+        uploader.status.send_modify(|status| status.pending += 1);
+    }
+}
+
+impl Filter {
+    fn position(&mut self, timestamp: DateTime<Utc>) -> Cursor {
+        let count = match self.last {
+            Some(last) if last.timestamp == timestamp => last.count + 1,
+            _ => 1,
+        };
+        let position = Cursor { timestamp, count };
+        self.last = Some(position);
+        position
+    }
+}
+
+/// Adds the fields EDSM asks for to tie an event to where it happened, taken from the event
+/// itself where it says, otherwise from what we knew just before it.
+fn with_transient_fields(mut event: Value, state: &State, market_id: Option<&Value>) -> Value {
+    let Value::Object(map) = &mut event else { return event };
+    let location = &state.location;
+
+    let address = map.get("SystemAddress").cloned()
+        .or_else(|| (location.system_address != 0).then(|| json!(location.system_address)));
+    let name = map.get("StarSystem").cloned()
+        .or_else(|| (!location.system_name.is_empty()).then(|| json!(location.system_name)));
+    let coordinates = map.get("StarPos").cloned()
+        .or_else(|| (location.star_pos.len() == 3).then(|| json!(location.star_pos)));
+    let market_id = map.get("MarketID").cloned()
+        .or_else(|| market_id.cloned());
+    let station_name = map.get("StationName").cloned()
+        .or_else(|| location.docked.then(|| location.station_name.as_ref().map(|s| json!(s))).flatten());
+
+    insert(map, "_systemAddress", address);
+    insert(map, "_systemName", name);
+    insert(map, "_systemCoordinates", coordinates);
+    insert(map, "_marketId", market_id);
+    insert(map, "_stationName", station_name);
+    event
+}
+
+fn insert(map: &mut Map<String, Value>, key: &str, value: Option<Value>) {
+    if let Some(value) = value {
+        map.insert(key.into(), value);
+    }
+}
+
+/// Optional fields serialize as null, but the journal leaves them out.
+fn drop_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(map.into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(key, value)| (key, drop_nulls(value)))
+            .collect()),
+        Value::Array(items) => Value::Array(items.into_iter().map(drop_nulls).collect()),
+        other => other,
+    }
+}
+
+async fn upload(settings: EdsmSettings, mut queue: mpsc::UnboundedReceiver<Pending>) {
+    let Some(uploader) = UPLOADER.get() else { return };
+    let client = EdsmClient::default();
+
+    // nothing can be sent until we know what EDSM doesn't want
+    let discard: HashSet<Box<str>> = loop {
+        match client.get_journal_discard().await {
+            Ok(discard) => break discard.into_iter().collect(),
+            Err(e) => {
+                warn!("Failed to fetch EDSM discard list, trying again in {}s: {}", RETRY_LATER.as_secs(), e);
+                tokio::time::sleep(RETRY_LATER).await;
+            }
+        }
+    };
+
+    info!("Uploading journal to EDSM for CMDR {}", settings.commander_name);
+    let mut held: Option<Pending> = None;
+    loop {
+        let first = match held.take() {
+            Some(pending) => pending,
+            None => match queue.recv().await {
+                Some(pending) => pending,
+                None => return,
+            },
+        };
+
+        // gather whatever else turns up shortly after, as long as it came from the same game
+        let deadline = Instant::now() + BATCH_DELAY;
+        let mut batch = vec![first];
+        while batch.len() < BATCH_SIZE {
+            match tokio::time::timeout_at(deadline, queue.recv()).await {
+                Ok(Some(next)) if next.game == batch[0].game => batch.push(next),
+                Ok(Some(next)) => {
+                    held = Some(next);
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        let count = batch.len();
+        let position = batch[count - 1].position;
+        let game = batch[0].game.clone();
+        let events: Vec<Value> = batch.into_iter()
+            .map(|pending| pending.event)
+            .filter(|event| !discard.contains(event["event"].as_str().unwrap_or_default()))
+            .collect();
+        let sent = events.len() as u64;
+
+        if !events.is_empty() {
+            let upload = JournalUpload {
+                commander_name: settings.commander_name.clone(),
+                api_key: settings.api_key.clone(),
+                game_version: game.version,
+                game_build: game.build,
+                events,
+            };
+            if !send(&client, &upload, &uploader.status).await {
+                return;
+            }
+        }
+
+        position.save();
+        uploader.status.send_modify(|status| {
+            status.pending = status.pending.saturating_sub(count);
+            status.uploaded += sent;
+            status.last_upload = Some(Utc::now());
+            status.last_error = None;
+        });
+    }
+}
+
+/// Sends a batch, waiting and trying again for as long as the failure might clear up. Returns
+/// false if EDSM refused it, in which case uploading stops for the session.
+async fn send(client: &EdsmClient, upload: &JournalUpload, status: &watch::Sender<Status>) -> bool {
+    loop {
+        let error = match client.post_journal(upload).await {
+            Ok(response) if response.is_ok() => {
+                for (event, result) in upload.events.iter().zip(response.events.iter().flatten()) {
+                    if !result.is_ok() {
+                        warn!("EDSM didn't accept {}: {} {}", event["event"], result.msgnum, result.msg);
+                    }
+                }
+                debug!("Uploaded {} events to EDSM", upload.events.len());
+                return true;
+            }
+            Ok(response) => {
+                error!("EDSM refused the journal upload: {} {}", response.msgnum, response.msg);
+                status.send_modify(|status| {
+                    status.last_error = Some(format!("{} {}", response.msgnum, response.msg).into());
+                    status.stopped = true;
+                });
+                return false;
+            }
+            Err(e) => e,
+        };
+
+        let transient = matches!(&error, EdsmError::Request(e) if e.is_transient());
+        status.send_modify(|status| status.last_error = Some(error.to_string().into()));
+        if !transient {
+            error!("EDSM journal upload failed: {}", error);
+            status.send_modify(|status| status.stopped = true);
+            return false;
+        }
+
+        warn!("EDSM journal upload failed, trying again in {}s: {}", RETRY_LATER.as_secs(), error);
+        tokio::time::sleep(RETRY_LATER).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn events_in_the_same_second_are_counted() {
+        let second = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut filter = Filter::default();
+
+        let first = filter.position(second);
+        let next = filter.position(second);
+        let later = filter.position(second + chrono::Duration::seconds(1));
+
+        assert_eq!(first, Cursor { timestamp: second, count: 1 });
+        assert_eq!(next, Cursor { timestamp: second, count: 2 });
+        assert_eq!(later.count, 1);
+        assert!(first < next && next < later);
+    }
+
+    #[test]
+    fn transient_fields_prefer_the_event() {
+        let mut state = State::default();
+        state.location.system_address = 10477373803;
+        state.location.system_name = "Sol".into();
+        state.location.star_pos = vec![0.0, 0.0, 0.0];
+        state.location.docked = true;
+        state.location.station_name = Some("Abraham Lincoln".into());
+
+        let jump = json!({ "event": "FSDJump", "StarSystem": "Achenar", "SystemAddress": 164098653, "StarPos": [67.5, -119.46875, 24.84375] });
+        let jump = with_transient_fields(jump, &state, None);
+        assert_eq!(jump["_systemName"], "Achenar");
+        assert_eq!(jump["_systemAddress"], 164098653);
+        assert_eq!(jump["_systemCoordinates"], json!([67.5, -119.46875, 24.84375]));
+
+        let docked = json!({ "event": "MissionAccepted" });
+        let docked = with_transient_fields(docked, &state, Some(&json!(128016640)));
+        assert_eq!(docked["_systemName"], "Sol");
+        assert_eq!(docked["_stationName"], "Abraham Lincoln");
+        assert_eq!(docked["_marketId"], 128016640);
+    }
+}
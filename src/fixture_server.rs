@@ -0,0 +1,80 @@
+//! A local stand-in for the EDSM and Ardent APIs, so the client tests don't need the network.
+//!
+//! Each request is answered with a recorded JSON file from a fixture directory: the request path
+//! with `.json` appended, or for EDSM-style requests that name a system in the query string,
+//! `<path>/<systemName>.json`. Requests without a fixture get a 404.
+//!
+//! The client test suites run against the real services instead when the `live_tests` feature is
+//! enabled.
+
+use axum::extract::{Query, State};
+use axum::http::{StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub const EDSM_FIXTURES: &str = "src/test_data/edsm";
+pub const ARDENT_FIXTURES: &str = "src/test_data/ardent";
+
+/// Starts serving the given fixture directory on a free local port and returns its base URL.
+/// The server runs until the test's runtime shuts down.
+pub async fn start(fixtures: &str) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind fixture server");
+    let address = listener.local_addr().unwrap();
+
+    let app = Router::new()
+        .fallback(respond)
+        .with_state(PathBuf::from(fixtures));
+
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app).await;
+    });
+
+    format!("http://{}/", address)
+}
+
+async fn respond(
+    State(root): State<PathBuf>,
+    uri: Uri,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let path = fixture_path(&root, uri.path(), query.get("systemName").map(String::as_str));
+
+    match tokio::fs::read_to_string(&path).await {
+        Ok(body) => ([("content-type", "application/json")], body).into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+fn fixture_path(root: &Path, request_path: &str, system_name: Option<&str>) -> PathBuf {
+    let mut path = root.join(request_path.trim_matches('/'));
+    if let Some(name) = system_name {
+        path = path.join(name);
+    }
+    // not set_extension, which would replace anything after a dot in the last segment
+    let mut path = path.into_os_string();
+    path.push(".json");
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_map_to_fixture_files() {
+        let root = PathBuf::from(EDSM_FIXTURES);
+
+        assert_eq!(
+            fixture_path(&root, "/api-system-v1/bodies", Some("Sol")),
+            root.join("api-system-v1/bodies/Sol.json")
+        );
+        assert_eq!(
+            fixture_path(&root, "/system/name/Sol/nearby", None),
+            root.join("system/name/Sol/nearby.json")
+        );
+    }
+}
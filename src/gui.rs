@@ -27,7 +27,7 @@ impl Gui {
             .map(|v| !v.is_empty())
             .unwrap_or(false);
 
-        if !has_logs {
+        if !has_logs && state.replay.is_none() {
             waiting_spinner()
         } else if !state.journal_loaded {
             loading_bar(state)
@@ -90,18 +90,32 @@ fn waiting_spinner<'a>() -> Element<'a, Message> {
 }
 
 fn loading_bar(state: &State) -> Element<'_, Message> {
+    // fall back to message timestamps until the loader has told us how many files there are
+    let bar = match &state.load_progress {
+        Some(progress) => progress_bar(
+            0f32..=progress.file_count.max(1) as f32,
+            progress.files_done as f32
+        ),
+        None => progress_bar(
+            state.first_message_timestamp as f32..=Utc::now().timestamp() as f32,
+            state.latest_message_timestamp as f32
+        ),
+    };
+
     centered![
-        row![
-            progress_bar(
-                state.first_message_timestamp as f32..=Utc::now().timestamp() as f32,
-                state.latest_message_timestamp as f32
-            )
-            .length(Fill)
-            .style(style::progress_bar),
-        ],
+        row![bar.length(Fill).style(style::progress_bar)],
         row![
             column![text("Loading...").color(ORANGE).size(32)],
             column![].width(Fill),
+            column![
+                text(state.load_progress
+                    .as_ref()
+                    .map(|p| format!("{} ({} of {})", p.file_name, p.files_done + 1, p.file_count))
+                    .unwrap_or_default())
+                    .color(ORANGE)
+                    .size(16)
+            ]
+            .padding([12, 16]),
             column![
                 text(state.latest_message_timestamp_formatted.as_ref())
                     .color(ORANGE)
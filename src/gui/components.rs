@@ -16,9 +16,13 @@
 //! - `empty_placeholder`:
 //!   Creates a placeholder
 use crate::gui::Message;
-use crate::theme::{style, GRAY, ORANGE};
-use iced::widget::{column, row, scrollable, text, Column, Row, Scrollable};
-use iced::{Element, Fill, Right};
+use crate::message::Gui;
+use crate::state::queries::{Source, Summary};
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE, RED, YELLOW};
+use chrono::Utc;
+use iced::widget::{button, column, row, scrollable, text, Column, Row, Scrollable};
+use iced::{Center, Element, Fill, Right};
 use iced::padding::right;
 
 /// Creates a sub-header component as a `Row` containing a single piece of styled text.
@@ -105,6 +109,42 @@ pub fn empty_placeholder(label: &str) -> Column<'_, Message> {
     ]
 }
 
+/// Shows whether the EDSM/Ardent data behind a pane is loading, failed to load or is getting old,
+/// with a button to fetch it again. Empty while the data is current.
+pub fn query_status<'a>(state: &'a State, sources: &'static [Source]) -> Row<'a, Message> {
+    if state.queries.system_address != state.location.system_address {
+        return row![];
+    }
+
+    let (label, color) = match state.queries.summary(sources, Utc::now()) {
+        Summary::Current => return row![],
+        Summary::Loading => {
+            return row![text("Loading...").size(14).color(GRAY)].padding([4, 8]);
+        }
+        Summary::Failed(failures) => {
+            let failures: Vec<String> = failures.iter()
+                .map(|(source, reason)| format!("{} ({})", source.label(), reason))
+                .collect();
+            (format!("Couldn't load {}", failures.join(", ")), RED)
+        }
+        Summary::Stale(fetched_at) => {
+            let minutes = (Utc::now() - fetched_at).num_minutes();
+            let age = if minutes < 120 { format!("{} minutes", minutes) } else { format!("{} hours", minutes / 60) };
+            (format!("Last updated {} ago", age), YELLOW)
+        }
+    };
+
+    row![
+        text(label).size(14).color(color).width(Fill),
+        button(text("Retry").size(14))
+            .on_press(Message::Gui(Gui::RetryQueries(sources)))
+            .style(style::button),
+    ]
+    .align_y(Center)
+    .spacing(8)
+    .padding([4, 8])
+}
+
 /// Creates a scrollable list container from a vector of elements.
 ///
 /// This function takes a vector of elements that implement the `Into<Element<'a, Message>>` trait
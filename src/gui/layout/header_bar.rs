@@ -1,14 +1,22 @@
 use crate::gui::Message;
 use crate::state::State;
-use crate::theme::ORANGE;
+use crate::theme::{GRAY, ORANGE};
 use iced::widget::{column, row, text, Row};
 use iced::{Fill, Left, Right};
+use thousands::Separable;
 
 pub fn header_bar(state: &State) -> Row<'_, Message> {
     row![
         column![
             text(state.commander_name.as_ref()).size(30).color(ORANGE),
             text(state.credits.as_ref()).size(30),
+            if state.exploration.unsold_value() > 0 {
+                row![text(format!(
+                    "{} CR unsold data",
+                    state.exploration.unsold_value().separate_with_commas()
+                )).size(16).color(GRAY)]
+            }
+            else { row![] },
         ]
         .width(Fill)
         .align_x(Left),
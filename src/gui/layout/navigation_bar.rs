@@ -5,7 +5,8 @@ use crate::theme::{style, GRAY, ORANGE, WHITE};
 use iced::widget::button::{Status, Style};
 use iced::widget::{button, column, row, svg, text, Row};
 use iced::{Color, Fill, Right, Theme};
-use crate::message::Gui::{NavigateTo, NavigateToCustomScreen, ToggleFullscreen};
+use crate::journal::replay::Control;
+use crate::message::Gui::{NavigateTo, NavigateToCustomScreen, Replay, ToggleFullscreen};
 
 pub fn navigation_bar(state: &State) -> Row<'_, Message> {
     let fullscreen_icon = if state.layout.fullscreen {
@@ -69,9 +70,29 @@ pub fn navigation_bar(state: &State) -> Row<'_, Message> {
         column![].into()
     };
 
+    let replay_col: iced::Element<'_, Message> = if let Some(replay) = &state.replay {
+        let (label, control) = if replay.paused { ("Play", Control::Play) } else { ("Pause", Control::Pause) };
+        let mut step = button("Step").style(default_style);
+        if replay.paused {
+            step = step.on_press(Message::Gui(Replay(Control::Step)));
+        }
+
+        row![
+            text(format!("Replay x{}", replay.speed)).size(12).color(GRAY),
+            button(label).on_press(Message::Gui(Replay(control))).style(default_style),
+            step,
+        ]
+        .spacing(4)
+        .padding([0, 4])
+        .into()
+    } else {
+        column![].into()
+    };
+
     row![
         row(custom_buttons),
         column![].width(Fill),
+        replay_col,
         info_col,
         column![fullscreen_button].align_x(Right).padding([0, 4]),
         column![settings_button].align_x(Right).padding([0, 4])
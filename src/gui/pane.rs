@@ -20,6 +20,14 @@ mod route;
 mod powerplay;
 mod system_scanner;
 mod body_signals;
+mod carrier;
+mod cargo;
+mod trade;
+mod exobiology;
+mod mining;
+mod diagnostics;
+mod status;
+mod surface_navigation;
 
 pub use location::*;
 pub use missions::*;
@@ -37,6 +45,14 @@ pub use route::*;
 pub use powerplay::*;
 pub use system_scanner::*;
 pub use body_signals::*;
+pub use carrier::*;
+pub use cargo::*;
+pub use trade::*;
+pub use exobiology::*;
+pub use mining::*;
+pub use diagnostics::*;
+pub use status::*;
+pub use surface_navigation::*;
 
 use crate::gui::Message;
 use crate::state::State;
@@ -85,6 +101,14 @@ define_panes! {
     LogJournal,
     SystemScanner,
     BodySignals,
+    Carrier,
+    Cargo,
+    Trade,
+    Exobiology,
+    Mining,
+    Diagnostics,
+    ShipStatus,
+    SurfaceNavigation,
 }
 
 pub fn defaults() -> Vec<&'static dyn Type> {
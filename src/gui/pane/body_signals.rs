@@ -1,8 +1,9 @@
 use crate::bordered_list_item;
-use crate::gui::components::{empty_placeholder, scroll_list};
+use crate::gui::components::{empty_placeholder, query_status, scroll_list};
 use crate::gui::pane;
 use crate::message::Message;
 use crate::state::fss;
+use crate::state::queries::Source;
 use crate::state::State;
 use crate::theme::{style, ORANGE, WHITE};
 use iced::widget::tooltip::Position;
@@ -12,17 +13,21 @@ use std::collections::{HashMap, HashSet};
 
 pub struct BodySignals;
 
+const SOURCES: &[Source] = &[Source::Bodies];
+
 impl BodySignals {
     fn render_node<'a>(
         id: u8,
         depth: u16,
+        state: &'a State,
         bodies: &'a HashMap<u8, fss::Body>,
         children_map: &HashMap<Option<u8>, Vec<u8>>,
         rows: &mut Vec<Element<'a, Message>>,
     ) {
         let indentation = (depth * 16) as f32;
         let content: Element<'a, Message> = if let Some(body) = bodies.get(&id) {
-            body_details(body).into()
+            let analysed = state.exobiology.analysed_count(state.location.system_address, id);
+            body_details(body, analysed).into()
         } else {
             unknown_body_details().into()
         };
@@ -35,7 +40,7 @@ impl BodySignals {
 
         if let Some(children) = children_map.get(&Some(id)) {
             for &child_id in children {
-                Self::render_node(child_id, depth + 1, bodies, children_map, rows);
+                Self::render_node(child_id, depth + 1, state, bodies, children_map, rows);
             }
         }
     }
@@ -77,18 +82,18 @@ impl pane::Type for BodySignals {
                 let mut sorted_roots = roots.clone();
                 sorted_roots.sort();
                 for &root_id in &sorted_roots {
-                    Self::render_node(root_id, 0, &system_scans.bodies, &children_map, &mut rows);
+                    Self::render_node(root_id, 0, state, &system_scans.bodies, &children_map, &mut rows);
                 }
             }
 
-            column![scroll_list(rows)].into()
+            column![query_status(state, SOURCES), scroll_list(rows)].into()
         } else {
-            empty_placeholder("No signals found").into()
+            column![query_status(state, SOURCES), empty_placeholder("No signals found")].into()
         }
     }
 }
 
-fn body_details(body: &fss::Body) -> Row<'_, Message> {
+fn body_details(body: &fss::Body, analysed: usize) -> Row<'_, Message> {
     bordered_list_item![
         if let Some(icon) = body.primary_icon() {
             column![tooltip(
@@ -119,10 +124,18 @@ fn body_details(body: &fss::Body) -> Row<'_, Message> {
                     .signals
                     .iter()
                     .map(|sig| {
-                        text(format!("{}: {}", sig.kind, sig.count))
-                            .size(12)
-                            .color(WHITE)
-                            .into()
+                        if sig.kind.as_ref() == "Biological" && analysed > 0 {
+                            let complete = analysed >= sig.count as usize;
+                            text(format!("{}: {}/{}", sig.kind, analysed, sig.count))
+                                .size(12)
+                                .color(if complete { ORANGE } else { WHITE })
+                                .into()
+                        } else {
+                            text(format!("{}: {}", sig.kind, sig.count))
+                                .size(12)
+                                .color(WHITE)
+                                .into()
+                        }
                     })
                     .collect::<Vec<_>>())
                 .spacing(8)
@@ -0,0 +1,66 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE, RED};
+use iced::widget::{column, progress_bar, row, text};
+use iced::{Element, Fill};
+
+pub struct Cargo;
+
+impl pane::Type for Cargo {
+    fn title(&self) -> &'static str { "Cargo" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let used = state.cargo.used();
+        let capacity = state.ship_loadout.cargo_capacity;
+
+        let header = column![
+            details("Capacity Used", format!("{} / {} T", used, capacity)),
+            row![
+                progress_bar(0f32..=capacity.max(1) as f32, used as f32)
+                    .girth(8)
+                    .style(style::progress_bar)
+            ]
+            .padding([4, 8]),
+        ];
+
+        if state.cargo.items.is_empty() {
+            return column![header, empty_placeholder("Cargo hold is empty")].into();
+        }
+
+        let rows = state.cargo.items
+            .iter()
+            .map(|item| {
+                let mission = item.mission_id
+                    .and_then(|id| state.missions.iter().find(|m| m.mission_id == id))
+                    .map(|m| m.name.as_ref());
+
+                row![
+                    column![
+                        text(item.name.as_ref()).size(16).color(ORANGE),
+                        if let Some(mission) = mission {
+                            text(mission).size(12).color(GRAY)
+                        } else if item.mission_id.is_some() {
+                            text("Mission cargo").size(12).color(GRAY)
+                        } else {
+                            text("")
+                        }
+                    ]
+                    .width(Fill),
+                    column![
+                        if item.stolen > 0 {
+                            text(format!("{} stolen", item.stolen)).size(14).color(RED)
+                        } else {
+                            text("")
+                        }
+                    ]
+                    .padding([0, 8]),
+                    column![text(format!("{} T", item.count)).size(16)],
+                ]
+                .padding([4, 8])
+            })
+            .collect();
+
+        column![header, scroll_list(rows)].height(Fill).into()
+    }
+}
@@ -0,0 +1,134 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::carrier::{OrderKind, PendingJump};
+use crate::state::{carrier, State};
+use crate::theme::style;
+use iced::widget::{column, scrollable, Column};
+use iced::Element;
+use thousands::Separable;
+
+pub struct Carrier;
+
+impl pane::Type for Carrier {
+    fn title(&self) -> &'static str { "Carrier" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        if state.carriers.is_empty() {
+            return column![empty_placeholder("No Fleet Carriers")].into();
+        }
+
+        let mut carriers: Vec<_> = state.carriers.values().collect();
+        carriers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        column![
+            scrollable(column(
+                carriers
+                    .into_iter()
+                    .map(|c| column![summary(c), crew_services(c), trade_orders(c), materials(c)])
+                    .map(Element::from)
+            ))
+            .style(style::scrollable)
+        ]
+        .into()
+    }
+}
+
+fn summary(carrier: &carrier::Carrier) -> Column<'_, Message> {
+    let mut col = column![
+        sub_header(carrier.name.as_ref()),
+        details("Callsign", carrier.callsign.as_ref()),
+        details("Location", location(carrier)),
+    ];
+
+    if let Some(jump) = &carrier.pending_jump {
+        col = col.push(details("Jumping To", pending_jump(jump)));
+    }
+
+    col.push(details("Fuel", format!("{} / 1000 T", carrier.fuel_level)))
+        .push(details("Tritium Reserve", format!("{} T", carrier.tritium_reserve)))
+        .push(details("Jump Range", format!("{:.0} ly", carrier.jump_range)))
+        .push(details("Balance", format!("{} CR", carrier.finance.balance.separate_with_commas())))
+        .push(details("Reserve", format!("{} CR", carrier.finance.reserve.separate_with_commas())))
+        .push(details("Available", format!("{} CR", carrier.finance.available.separate_with_commas())))
+        .push(details("Weekly Upkeep", format!("{} CR", carrier.upkeep().separate_with_commas())))
+        .push(details("Docking Access", carrier.docking_access.clone().unwrap_or_default()))
+        .push(details("Free Space", format!("{} / {} T", carrier.space_free, carrier.space_total)))
+        .push(details(
+            "Decommission",
+            carrier.pending_decommission
+                .map(|d| d.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default()
+        ))
+}
+
+fn location(carrier: &carrier::Carrier) -> String {
+    match &carrier.body_name {
+        Some(body) if body.as_ref() != carrier.system_name.as_ref() => format!("{} ({})", carrier.system_name, body),
+        _ => carrier.system_name.to_string(),
+    }
+}
+
+fn pending_jump(jump: &PendingJump) -> String {
+    match jump.countdown() {
+        Some(remaining) => format!(
+            "{} in {:02}:{:02}",
+            jump.system_name,
+            remaining.num_minutes(),
+            remaining.num_seconds() % 60
+        ),
+        None => format!("{} (jumping)", jump.system_name),
+    }
+}
+
+fn crew_services(carrier: &carrier::Carrier) -> Column<'_, Message> {
+    if carrier.crew_services.is_empty() {
+        return column![];
+    }
+
+    let mut col = column![sub_header("Crew Services")];
+    for service in &carrier.crew_services {
+        let status = if service.active { "Active" } else { "Suspended" };
+        col = col.push(details(
+            service.role.as_ref(),
+            match &service.crew_name {
+                Some(name) => format!("{} ({})", name, status),
+                None => status.to_string(),
+            },
+        ));
+    }
+    col
+}
+
+fn trade_orders(carrier: &carrier::Carrier) -> Column<'_, Message> {
+    if carrier.trade_orders.is_empty() {
+        return column![];
+    }
+
+    let mut col = column![sub_header("Trade Orders")];
+    for order in &carrier.trade_orders {
+        let verb = if order.kind == OrderKind::Buy { "Buying" } else { "Selling" };
+        col = col.push(details(
+            order.name.as_ref(),
+            format!(
+                "{} {} T @ {} CR{}",
+                verb,
+                order.quantity.separate_with_commas(),
+                order.price.separate_with_commas(),
+                if order.black_market { " (Black Market)" } else { "" }
+            ),
+        ));
+    }
+    col
+}
+
+fn materials(carrier: &carrier::Carrier) -> Column<'_, Message> {
+    if carrier.materials.is_empty() {
+        return column![];
+    }
+
+    let mut col = column![sub_header("Bar Materials")];
+    for material in &carrier.materials {
+        col = col.push(details(material.name.as_ref(), material.count.to_string()));
+    }
+    col
+}
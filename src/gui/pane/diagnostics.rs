@@ -0,0 +1,69 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE, RED, WHITE};
+use iced::widget::text::Wrapping;
+use iced::widget::{column, scrollable, text, Column};
+use iced::{Element, Fill};
+
+pub struct Diagnostics;
+
+impl pane::Type for Diagnostics {
+    fn title(&self) -> &'static str { "Diagnostics" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let diagnostics = &state.diagnostics;
+
+        if diagnostics.is_empty() {
+            return column![empty_placeholder("Every journal line was read")].into();
+        }
+
+        column![
+            scrollable(column![
+                unknown_events(state),
+                unparsed_lines(state),
+            ])
+            .style(style::scrollable)
+        ]
+        .into()
+    }
+}
+
+fn unknown_events(state: &State) -> Column<'_, Message> {
+    let diagnostics = &state.diagnostics;
+    if diagnostics.unknown_events.is_empty() {
+        return column![];
+    }
+
+    let mut col = column![sub_header("Unknown Events")];
+    for (name, count) in &diagnostics.unknown_events {
+        col = col.push(details(name, count.to_string()));
+    }
+    col
+}
+
+fn unparsed_lines(state: &State) -> Column<'_, Message> {
+    let diagnostics = &state.diagnostics;
+    if diagnostics.unparsed_count == 0 {
+        return column![];
+    }
+
+    let mut col = column![
+        sub_header("Unparsed Lines"),
+        details("Total", diagnostics.unparsed_count.to_string()),
+    ];
+    for line in diagnostics.unparsed.iter().rev() {
+        col = col.push(
+            column![
+                text(line.file.as_ref()).size(12).color(GRAY),
+                text(line.error.as_ref()).size(14).color(RED),
+                text(line.line.as_ref()).size(12).color(WHITE).wrapping(Wrapping::None),
+            ]
+            .padding([4, 8]),
+        );
+    }
+    if diagnostics.unparsed_count > diagnostics.unparsed.len() as u64 {
+        col = col.push(text("Older lines are in the log").size(12).color(ORANGE).width(Fill));
+    }
+    col
+}
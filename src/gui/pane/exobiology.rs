@@ -0,0 +1,116 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::exobiology::{Species, Stage};
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE, RED, WHITE};
+use iced::widget::{column, row, scrollable, text, Column};
+use iced::{Element, Fill};
+use thousands::Separable;
+
+pub struct Exobiology;
+
+impl pane::Type for Exobiology {
+    fn title(&self) -> &'static str { "Exobiology" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let exobiology = &state.exobiology;
+        let current_body = state.system_scans
+            .get(&state.location.system_address)
+            .and_then(|scan| scan.bodies.values().find(|b| b.name == state.location.body_name));
+
+        let species = current_body
+            .map(|body| exobiology.species(state.location.system_address, body.id))
+            .unwrap_or_default();
+
+        if species.is_empty() && exobiology.unsold.is_empty() {
+            return column![empty_placeholder("No organics scanned")].into();
+        }
+
+        let mut col = column![];
+
+        if let Some(body) = current_body {
+            let expected = body.signals
+                .iter()
+                .find(|s| s.kind.as_ref() == "Biological")
+                .map(|s| s.count as usize);
+
+            col = col.push(sub_header(body.name.as_ref())).push(details(
+                "Analysed",
+                format!(
+                    "{} / {}",
+                    exobiology.analysed_count(state.location.system_address, body.id),
+                    expected.map(|c| c.to_string()).unwrap_or("?".into())
+                ),
+            ));
+
+            for s in species {
+                col = col.push(species_row(s, state));
+            }
+        }
+
+        col = col.push(unsold(state));
+
+        column![scrollable(col).style(style::scrollable)].into()
+    }
+}
+
+fn species_row<'a>(species: &'a Species, state: &'a State) -> Element<'a, Message> {
+    let exobiology = &state.exobiology;
+    let distance = match (exobiology.position, exobiology.planet_radius) {
+        (Some(position), Some(radius)) if species.stage != Stage::Analysed => species
+            .nearest_sample(&position, radius)
+            .map(|nearest| (nearest, species.can_sample_at(&position, radius))),
+        _ => None,
+    };
+
+    row![
+        column![
+            text(species.name.as_ref()).size(16).color(ORANGE),
+            text(species.variant.as_deref().unwrap_or_default()).size(12).color(GRAY),
+        ]
+        .width(Fill),
+        column![
+            text(format!("{} / 3", species.stage.samples_taken())).size(16).color(WHITE),
+            match distance {
+                Some((nearest, clear)) => text(format!(
+                    "{:.0} / {} m",
+                    nearest,
+                    species.colony_distance().unwrap_or_default()
+                ))
+                .size(12)
+                .color(if clear { ORANGE } else { RED }),
+                None => text(
+                    species.value().map(|v| format!("{} CR", v.separate_with_commas())).unwrap_or_default()
+                )
+                .size(12)
+                .color(GRAY),
+            }
+        ],
+    ]
+    .padding([4, 8])
+    .into()
+}
+
+fn unsold(state: &State) -> Column<'_, Message> {
+    let exobiology = &state.exobiology;
+    if exobiology.unsold.is_empty() {
+        return column![];
+    }
+
+    let mut col = column![
+        sub_header("Unsold Samples"),
+        details("Total Value", format!("{} CR", exobiology.unsold_value().separate_with_commas())),
+    ];
+
+    for sample in &exobiology.unsold {
+        col = col.push(details(
+            sample.name.as_ref(),
+            format!(
+                "{} CR{}",
+                sample.value.separate_with_commas(),
+                if sample.first_footfall { " (First Footfall)" } else { "" }
+            ),
+        ));
+    }
+    col
+}
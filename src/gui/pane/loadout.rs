@@ -14,7 +14,7 @@ impl pane::Type for Loadout {
         column![
             scroll_list![
                 sub_header("Suit"),
-                details("Name", state.suit_loadout.suit_name),
+                details("Name", state.suit_loadout.suit_name.as_ref()),
                 details("Class", state.suit_loadout.class.to_string()),
                 details("Loadout", state.suit_loadout.loadout_name.as_ref()),
                 column(
@@ -22,7 +22,7 @@ impl pane::Type for Loadout {
                         .suit_loadout
                         .suit_mods
                         .iter()
-                        .map(|mod_name| { details("Modification", *mod_name).into() })
+                        .map(|mod_name| { details("Modification", mod_name.as_ref()).into() })
                 )
                 .padding(8),
                 sub_header("Weapons"),
@@ -34,7 +34,7 @@ impl pane::Type for Loadout {
                             module
                                 .weapon_mods
                                 .iter()
-                                .map(|mod_name| { details("Modification", *mod_name).into() })
+                                .map(|mod_name| { details("Modification", mod_name.as_ref()).into() })
                         )
                         .padding([0, 16])
                     ]
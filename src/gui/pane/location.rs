@@ -1,6 +1,7 @@
 use crate::font::EUROSTILE;
 use crate::gui::components::*;
 use crate::gui::{pane, Message};
+use crate::state::queries::Source;
 use crate::state::State;
 use crate::theme::{style, GRAY};
 use iced::widget::{column, row, scrollable, text, Column};
@@ -9,11 +10,14 @@ use thousands::Separable;
 
 pub struct Location;
 
+const SOURCES: &[Source] = &[Source::Stations, Source::Traffic, Source::Deaths, Source::NearbySystems];
+
 impl pane::Type for Location {
     fn title(&self) -> &'static str { "Location" }
 
     fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
         column![
+            query_status(state, SOURCES),
             scrollable(column![
                 row![system(state), powerplay(state)],
                 station_summary(state),
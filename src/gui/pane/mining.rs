@@ -0,0 +1,109 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::mining::Session;
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE};
+use iced::widget::{column, row, scrollable, text, Column};
+use iced::{Element, Fill};
+
+pub struct Mining;
+
+impl pane::Type for Mining {
+    fn title(&self) -> &'static str { "Mining" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let mining = &state.mining;
+
+        if mining.current.is_none() && mining.history.is_empty() {
+            return column![empty_placeholder("No mining sessions")].into();
+        }
+
+        let mut col = column![];
+
+        if let Some(session) = &mining.current {
+            col = col.push(session_details("Current Session", session));
+        }
+
+        if !mining.history.is_empty() {
+            col = col.push(sub_header("Previous Sessions"));
+            for session in mining.history.iter().rev().take(20) {
+                col = col.push(history_row(session));
+            }
+        }
+
+        column![scrollable(col).style(style::scrollable)].into()
+    }
+}
+
+fn session_details<'a>(title: &'a str, session: &'a Session) -> Column<'a, Message> {
+    let mut col = column![
+        sub_header(title),
+        details("Location", session.location.as_ref()),
+        details("Tons Refined", format!("{} T", session.tons_refined())),
+        details(
+            "Tons per Hour",
+            session.tons_per_hour().map(|t| format!("{:.1} T/h", t)).unwrap_or_default()
+        ),
+        details("Asteroids Prospected", session.prospected.len().to_string()),
+        details("Motherlodes", session.motherlodes().to_string()),
+        details("Asteroids Cracked", session.asteroids_cracked.to_string()),
+        details(
+            "Limpets Used",
+            format!(
+                "{} ({} prospector, {} collector)",
+                session.limpets_used(),
+                session.prospector_limpets,
+                session.collector_limpets
+            )
+        ),
+        details("Fragments Collected", session.fragments_collected.to_string()),
+    ];
+
+    if !session.refined.is_empty() {
+        col = col.push(sub_header("Refined"));
+        for (name, count) in &session.refined {
+            col = col.push(details(name.as_ref(), format!("{} T", count)));
+        }
+    }
+
+    let materials = session.material_summary();
+    if !materials.is_empty() {
+        col = col.push(sub_header("Prospected Materials"));
+        for material in materials {
+            col = col.push(
+                row![
+                    column![
+                        text(material.name.to_string()).size(16).color(ORANGE),
+                        text(format!("{} asteroids", material.asteroids)).size(12).color(GRAY),
+                    ]
+                    .width(Fill),
+                    column![
+                        text(format!("{:.1}% avg", material.average_percentage)).size(16),
+                        text(format!("{:.1}% best", material.best_percentage)).size(12).color(GRAY),
+                    ],
+                ]
+                .padding([4, 8]),
+            );
+        }
+    }
+
+    col
+}
+
+fn history_row(session: &Session) -> Element<'_, Message> {
+    row![
+        column![
+            text(session.location.as_ref()).size(16).color(ORANGE),
+            text(session.started.format("%Y-%m-%d %H:%M").to_string()).size(12).color(GRAY),
+        ]
+        .width(Fill),
+        column![
+            text(format!("{} T", session.tons_refined())).size(16),
+            text(session.tons_per_hour().map(|t| format!("{:.1} T/h", t)).unwrap_or_default())
+                .size(12)
+                .color(GRAY),
+        ],
+    ]
+    .padding([4, 8])
+    .into()
+}
@@ -1,10 +1,10 @@
-use crate::gui::components::{empty_placeholder, scroll_list};
+use crate::gui::components::{details, empty_placeholder, scroll_list};
 use crate::gui::{pane, Message};
 use crate::image::FUEL_STAR_PNG;
 use crate::state::State;
-use crate::theme::{style, RED};
+use crate::theme::{style, ORANGE, RED};
 use iced::widget::image::Handle;
-use iced::widget::{column, container, image, row, text, Row};
+use iced::widget::{column, container, image, row, text, Column, Row};
 use iced::{Element, Fill};
 
 pub struct Route;
@@ -17,6 +17,9 @@ impl pane::Type for Route {
             return column![empty_placeholder("No current route")].into();
         }
 
+        let capacity = state.ship_loadout.fuel_capacity.main;
+        let runs_dry_at = state.fuel.runs_dry_at(&state.nav_route, &state.location.star_pos, capacity);
+
         let mut rows: Vec<Row<Message>> = Vec::new();
 
         for i in 0..state.nav_route.len() {
@@ -43,6 +46,13 @@ impl pane::Type for Route {
                 star_type_text = star_type_text.color(RED);
             }
 
+            if runs_dry_at == Some(i) {
+                rows.push(
+                    row![text("Fuel runs out before this jump").color(RED)]
+                        .padding([0, 16])
+                );
+            }
+
             rows.push(
                 row![
                     container(row![
@@ -61,6 +71,27 @@ impl pane::Type for Route {
             );
         }
 
-        column![scroll_list(rows)].height(Fill).into()
+        column![fuel_summary(state, runs_dry_at.is_some()), scroll_list(rows)].height(Fill).into()
+    }
+}
+
+fn fuel_summary(state: &State, runs_dry: bool) -> Column<'_, Message> {
+    let fuel = &state.fuel;
+    let capacity = state.ship_loadout.fuel_capacity.main;
+
+    let mut summary = column![
+        details("Fuel", fuel.main
+            .map(|main| if capacity > 0.0 { format!("{:.2} / {} T", main, capacity) } else { format!("{:.2} T", main) })
+            .unwrap_or_default()),
+        details("Reservoir", fuel.reservoir.map(|reservoir| format!("{:.2} T", reservoir)).unwrap_or_default()),
+        details("Fuel per Jump", fuel.fuel_per_jump().map(|used| format!("{:.2} T", used)).unwrap_or_default()),
+        details("Scoop Rate", fuel.scoop_rate.map(|rate| format!("{:.2} T/s", rate)).unwrap_or_default()),
+    ];
+
+    if runs_dry {
+        summary = summary.push(
+            row![text("Fuel runs out along this route").color(ORANGE)].padding([0, 16])
+        );
     }
+    summary
 }
@@ -0,0 +1,179 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::status::{Flag, Flag2, Status};
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE, RED};
+use iced::widget::{column, row, scrollable, text, Column, Row};
+use iced::{Element, Fill};
+
+/// Indicators per row
+const COLUMNS: usize = 3;
+
+pub struct ShipStatus;
+
+impl pane::Type for ShipStatus {
+    fn title(&self) -> &'static str { "Status" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let status = &state.status;
+
+        if !status.is_live() {
+            return column![empty_placeholder("Waiting for the game")].into();
+        }
+
+        let system_address = state.location.system_address;
+        let (indicators, readings) = if status.on_foot() {
+            (on_foot_indicators(status), on_foot_readings(status, system_address))
+        } else {
+            (vehicle_indicators(status), vehicle_readings(status, system_address))
+        };
+
+        column![
+            scrollable(column![
+                indicator_grid(indicators),
+                readings,
+                surface_readings(status),
+            ])
+            .style(style::scrollable)
+        ]
+        .into()
+    }
+}
+
+/// A label that lights up while its flag is set; warnings light up red.
+struct Indicator {
+    label: &'static str,
+    on: bool,
+    warning: bool,
+}
+
+fn normal(label: &'static str, on: bool) -> Indicator {
+    Indicator { label, on, warning: false }
+}
+
+fn warning(label: &'static str, on: bool) -> Indicator {
+    Indicator { label, on, warning: true }
+}
+
+fn vehicle_indicators(status: &Status) -> Vec<Indicator> {
+    let flags = status.flags;
+    let mut indicators = vec![
+        normal("Landing Gear", flags.has(Flag::LandingGearDown)),
+        normal("Hardpoints", flags.has(Flag::HardpointsDeployed)),
+        normal("Cargo Scoop", flags.has(Flag::CargoScoopDeployed)),
+        normal("Lights", flags.has(Flag::LightsOn)),
+        normal("Night Vision", flags.has(Flag::NightVision)),
+        normal("Supercruise", flags.has(Flag::Supercruise)),
+        normal("Silent Running", flags.has(Flag::SilentRunning)),
+        normal("Mass Locked", flags.has(Flag::FsdMassLocked)),
+        normal("FSD Charging", flags.has(Flag::FsdCharging) || status.flags2.has(Flag2::FsdHyperdriveCharging)),
+        normal("FSD Cooldown", flags.has(Flag::FsdCooldown)),
+        normal("Flight Assist Off", flags.has(Flag::FlightAssistOff)),
+        normal("Scooping Fuel", flags.has(Flag::ScoopingFuel)),
+        warning("Shields Down", flags.has(Flag::InMainShip) && !flags.has(Flag::ShieldsUp)),
+        warning("Overheating", flags.has(Flag::OverHeating)),
+        warning("Low Fuel", flags.has(Flag::LowFuel)),
+        warning("In Danger", flags.has(Flag::IsInDanger)),
+        warning("Interdicted", flags.has(Flag::BeingInterdicted)),
+    ];
+
+    if status.in_srv() {
+        indicators.extend([
+            normal("Handbrake", flags.has(Flag::SrvHandbrake)),
+            normal("Drive Assist", flags.has(Flag::SrvDriveAssist)),
+            normal("High Beam", flags.has(Flag::SrvHighBeam)),
+            normal("Turret View", flags.has(Flag::SrvTurretView)),
+        ]);
+    }
+    indicators
+}
+
+fn on_foot_indicators(status: &Status) -> Vec<Indicator> {
+    let flags2 = status.flags2;
+    vec![
+        normal("Breathable", flags2.has(Flag2::BreathableAtmosphere)),
+        normal("Aiming", flags2.has(Flag2::AimDownSight)),
+        normal("Gliding", flags2.has(Flag2::GlideMode)),
+        warning("Low Oxygen", flags2.has(Flag2::LowOxygen)),
+        warning("Low Health", flags2.has(Flag2::LowHealth)),
+        warning("Cold", flags2.has(Flag2::Cold) || flags2.has(Flag2::VeryCold)),
+        warning("Hot", flags2.has(Flag2::Hot) || flags2.has(Flag2::VeryHot)),
+        warning("In Danger", status.flags.has(Flag::IsInDanger)),
+    ]
+}
+
+fn indicator_grid<'a>(indicators: Vec<Indicator>) -> Column<'a, Message> {
+    let mut grid = column![].padding([4, 8]).spacing(4);
+    for chunk in indicators.chunks(COLUMNS) {
+        let mut line: Row<'a, Message> = row![].spacing(8);
+        for indicator in chunk {
+            let color = match (indicator.on, indicator.warning) {
+                (false, _) => GRAY,
+                (true, false) => ORANGE,
+                (true, true) => RED,
+            };
+            line = line.push(text(indicator.label).size(14).color(color).width(Fill));
+        }
+        for _ in chunk.len()..COLUMNS {
+            line = line.push(column![].width(Fill));
+        }
+        grid = grid.push(line);
+    }
+    grid
+}
+
+fn vehicle_readings(status: &Status, system_address: u64) -> Column<'_, Message> {
+    column![
+        details("Pips (SYS / ENG / WEP)", status.pips
+            .map(|pips| format!(
+                "{} / {} / {}",
+                pips.systems as f32 / 2.0,
+                pips.engines as f32 / 2.0,
+                pips.weapons as f32 / 2.0
+            ))
+            .unwrap_or_default()),
+        details("Fire Group", status.fire_group
+            .map(|group| char::from(b'A' + group.min(25)).to_string())
+            .unwrap_or_default()),
+        details("Fuel", status.fuel_main.map(|fuel| format!("{:.2} T", fuel)).unwrap_or_default()),
+        details("Reservoir", status.fuel_reservoir.map(|fuel| format!("{:.2} T", fuel)).unwrap_or_default()),
+        details("Cargo", status.cargo.map(|cargo| format!("{} T", cargo)).unwrap_or_default()),
+        details("Focus", status.gui_focus.label().unwrap_or_default()),
+        details("Destination", destination(status, system_address)),
+    ]
+}
+
+fn on_foot_readings(status: &Status, system_address: u64) -> Column<'_, Message> {
+    column![
+        details("Oxygen", status.oxygen.map(|oxygen| format!("{:.0}%", oxygen * 100.0)).unwrap_or_default()),
+        details("Health", status.health.map(|health| format!("{:.0}%", health * 100.0)).unwrap_or_default()),
+        details("Temperature", status.temperature.map(|t| format!("{:.0} K", t)).unwrap_or_default()),
+        details("Weapon", status.selected_weapon.as_deref().unwrap_or_default()),
+        details("Destination", destination(status, system_address)),
+    ]
+}
+
+fn surface_readings(status: &Status) -> Column<'_, Message> {
+    if !status.flags.has(Flag::HasLatLong) {
+        return column![];
+    }
+
+    column![
+        sub_header(status.body_name.as_deref().unwrap_or("Surface")),
+        details("Latitude", status.latitude.map(|lat| format!("{:.4}°", lat)).unwrap_or_default()),
+        details("Longitude", status.longitude.map(|lon| format!("{:.4}°", lon)).unwrap_or_default()),
+        details("Heading", status.heading.map(|heading| format!("{:.0}°", heading)).unwrap_or_default()),
+        details("Altitude", status.altitude.map(|altitude| format!("{:.0} m", altitude)).unwrap_or_default()),
+        details("Gravity", status.gravity.map(|gravity| format!("{:.2} g", gravity)).unwrap_or_default()),
+        details("Radius", status.planet_radius.map(|radius| format!("{:.0} km", radius / 1000.0)).unwrap_or_default()),
+    ]
+}
+
+/// The destination's name, noting when it's in another system.
+fn destination(status: &Status, system_address: u64) -> String {
+    match &status.destination {
+        Some(destination) if destination.system != system_address => format!("{} (other system)", destination.name),
+        Some(destination) => destination.name.to_string(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,100 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::message::Gui;
+use crate::state::status::Flag;
+use crate::state::surface::{Surface, Target};
+use crate::state::State;
+use crate::theme::{style, GRAY, ORANGE};
+use iced::widget::{button, column, row, scrollable, text, Column, Row};
+use iced::{Center, Element, Fill};
+
+pub struct SurfaceNavigation;
+
+impl pane::Type for SurfaceNavigation {
+    fn title(&self) -> &'static str { "Surface Navigation" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let status = &state.status;
+
+        let Some(body) = status.body_name.as_deref().filter(|_| status.flags.has(Flag::HasLatLong)) else {
+            return column![empty_placeholder("Not near a planet surface")].into();
+        };
+
+        let targets = state.surface.targets(body, &state.location.stations);
+
+        let mut list = column![sub_header("Targets")];
+        if targets.is_empty() {
+            list = list.push(row![text("Nothing to navigate to on this body yet").size(14).color(GRAY)].padding([4, 8]));
+        }
+        for target in targets {
+            list = list.push(target_row(state, target));
+        }
+
+        column![
+            current_target(state),
+            row![
+                button(text("Bookmark Here").size(14))
+                    .on_press(Message::Gui(Gui::BookmarkSurfacePosition))
+                    .style(style::button),
+            ]
+            .padding([4, 8]),
+            scrollable(list).style(style::scrollable),
+        ]
+        .into()
+    }
+}
+
+fn current_target(state: &State) -> Column<'_, Message> {
+    let Some(target) = &state.surface.target else {
+        return column![row![text("Choose a target below").size(14).color(GRAY)].padding([4, 8])];
+    };
+
+    let mut col = column![sub_header(target.name.as_ref())];
+    let Some(heading) = Surface::heading_to(target, &state.status) else {
+        return col.push(details(target.kind.label(), format!("On {}", target.body)));
+    };
+
+    col = col
+        .push(details("Bearing", format!("{:.0}°", heading.bearing)))
+        .push(details("Distance", distance(heading.distance)));
+
+    if let Some(current) = state.status.heading {
+        let turn = heading.turn_from(current);
+        let direction = if turn.abs() < 1.0 {
+            "Dead ahead".to_string()
+        } else if turn < 0.0 {
+            format!("{:.0}° left", -turn)
+        } else {
+            format!("{:.0}° right", turn)
+        };
+        col = col.push(details("Turn", direction));
+    }
+    col
+}
+
+fn target_row(state: &State, target: Target) -> Row<'_, Message> {
+    let is_selected = state.surface.target.as_ref() == Some(&target);
+    let range = Surface::heading_to(&target, &state.status)
+        .map(|heading| distance(heading.distance))
+        .unwrap_or_default();
+
+    row![
+        text(target.kind.label()).size(14).color(ORANGE).width(120),
+        text(target.name.to_string()).size(14).width(Fill),
+        text(range).size(14),
+        button(text(if is_selected { "Clear" } else { "Go" }).size(14))
+            .on_press(Message::Gui(Gui::SelectSurfaceTarget(if is_selected { None } else { Some(target) })))
+            .style(style::button),
+    ]
+    .align_y(Center)
+    .spacing(8)
+    .padding([4, 8])
+}
+
+fn distance(metres: f64) -> String {
+    if metres < 10_000.0 {
+        format!("{:.0} m", metres)
+    } else {
+        format!("{:.1} km", metres / 1000.0)
+    }
+}
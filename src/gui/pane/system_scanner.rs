@@ -1,14 +1,18 @@
 use crate::bordered_list_item;
-use crate::gui::components::{empty_placeholder, scroll_list};
+use crate::gui::components::{empty_placeholder, query_status, scroll_list};
 use crate::gui::{pane, Message};
 use crate::state::fss;
+use crate::state::queries::Source;
 use crate::state::State;
 use crate::theme::{style, ORANGE, WHITE};
 use iced::widget::{column, container, progress_bar, row, svg, text, Row};
 use iced::{Element, Fill, Right};
+use thousands::Separable;
 
 pub struct SystemScanner;
 
+const SOURCES: &[Source] = &[Source::Bodies];
+
 impl SystemScanner {
     fn signal_details(signal: &fss::Signal) -> Row<'_, Message> {
         let icon = signal.get_icon();
@@ -101,6 +105,16 @@ impl pane::Type for SystemScanner {
                                 .size(14)
                                 .color(WHITE)
                             ]
+                            .padding(4),
+                            row![
+                                text(format!(
+                                    "Unsold Data: {} CR  Total: {} CR",
+                                    state.exploration.system_value(state.location.system_address).separate_with_commas(),
+                                    state.exploration.unsold_value().separate_with_commas()
+                                ))
+                                .size(14)
+                                .color(WHITE)
+                            ]
                             .padding(4)
                         ])
                         .style(style::bordered)
@@ -119,10 +133,10 @@ impl pane::Type for SystemScanner {
                 rows.push(Self::signal_details(s).into());
             }
 
-            column![scroll_list(rows)].into()
+            column![query_status(state, SOURCES), scroll_list(rows)].into()
         }
         else {
-            empty_placeholder("No signals").into()
+            column![query_status(state, SOURCES), empty_placeholder("No signals")].into()
         }
     }
 }
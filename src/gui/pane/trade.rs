@@ -0,0 +1,77 @@
+use crate::gui::components::*;
+use crate::gui::{pane, Message};
+use crate::state::{trade, State};
+use crate::theme::{style, GRAY, ORANGE, RED};
+use iced::widget::{column, row, scrollable, text, Column};
+use iced::{Element, Fill};
+use thousands::Separable;
+
+pub struct Trade;
+
+impl pane::Type for Trade {
+    fn title(&self) -> &'static str { "Trade" }
+
+    fn render<'a>(&self, state: &'a State) -> Element<'a, Message> {
+        let ledger = &state.trade_ledger;
+
+        if ledger.runs.is_empty() {
+            return column![empty_placeholder("No trades yet")].into();
+        }
+
+        column![
+            scrollable(column![
+                totals("Session", &ledger.session),
+                totals("Lifetime", &ledger.lifetime),
+                runs(ledger),
+            ])
+            .style(style::scrollable)
+        ]
+        .into()
+    }
+}
+
+fn totals<'a>(title: &'a str, totals: &trade::Totals) -> Column<'a, Message> {
+    column![
+        sub_header(title),
+        details("Profit", credits(totals.profit())),
+        details(
+            "Profit per Hour",
+            totals.profit_per_hour().map(|p| credits(p as i64)).unwrap_or_default()
+        ),
+        details("Tons Sold", format!("{} T", totals.tons.separate_with_commas())),
+        details("Revenue", credits(totals.revenue as i64)),
+    ]
+}
+
+fn runs(ledger: &trade::Ledger) -> Column<'_, Message> {
+    let mut col = column![sub_header("Recent Runs")];
+
+    for run in ledger.runs.iter().rev().take(20) {
+        let profit = run.profit();
+        col = col.push(
+            row![
+                column![
+                    text(run.station_name.as_deref().unwrap_or("Unknown Market")).size(16).color(ORANGE),
+                    text(run.finished.format("%Y-%m-%d %H:%M").to_string()).size(12).color(GRAY),
+                ]
+                .width(Fill),
+                column![
+                    text(credits(profit)).size(16).color(if profit < 0 { RED } else { ORANGE }),
+                    text(
+                        run.profit_per_hour()
+                            .map(|p| format!("{}/h", credits(p as i64)))
+                            .unwrap_or_default()
+                    )
+                    .size(12)
+                    .color(GRAY),
+                ],
+            ]
+            .padding([4, 8]),
+        );
+    }
+    col
+}
+
+fn credits(amount: i64) -> String {
+    format!("{} CR", amount.separate_with_commas())
+}
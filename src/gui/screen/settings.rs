@@ -1,5 +1,5 @@
 use crate::gui::{pane, Message};
-use crate::theme::{GRAY, ORANGE, style};
+use crate::theme::{GRAY, ORANGE, RED, style};
 use iced::widget::{
     Column,
     Row,
@@ -114,7 +114,8 @@ pub fn settings(state: &State) -> Row<'_, Message> {
                             state.layout.show_messages_days_limit as f64,
                             |v| Message::Gui(UpdateMessageLimit(v as u16))
                         ).step(1.0).style(style::slider),
-                    ].spacing(8).align_y(Center)
+                    ].spacing(8).align_y(Center),
+                    edsm_upload(state),
                 ].width(488),
                 column![].width(Fill)
             ].padding(16),
@@ -122,3 +123,24 @@ pub fn settings(state: &State) -> Row<'_, Message> {
         ]
     ]
 }
+
+/// How the EDSM journal upload is getting on, if it's enabled.
+fn edsm_upload(state: &State) -> Column<'_, Message> {
+    let Some(status) = &state.edsm_upload else { return column![] };
+
+    let mut summary = format!("{} events uploaded, {} waiting", status.uploaded, status.pending);
+    if let Some(at) = status.last_upload {
+        summary.push_str(&format!(", last at {}", at.with_timezone(&chrono::Local).format("%H:%M")));
+    }
+
+    let mut items = column![
+        row![text("EDSM journal upload:").size(16).color(GRAY)],
+        row![text(summary).size(14).color(ORANGE)],
+    ]
+    .padding([8, 0]);
+    if let Some(error) = &status.last_error {
+        let label = if status.stopped { format!("Stopped: {}", error) } else { format!("Retrying: {}", error) };
+        items = items.push(text(label).size(14).color(RED));
+    }
+    items
+}
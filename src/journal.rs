@@ -1,7 +1,9 @@
 //!
-use log::{error, info};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use log::{error, info, warn};
 use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
 use std::fs::{File, OpenOptions};
+use std::collections::VecDeque;
 use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
@@ -10,8 +12,11 @@ use thiserror::Error;
 pub(crate) use crate::journal::event::Event;
 use crate::message::Message;
 
+pub mod checkpoint;
 pub mod event;
 pub mod format;
+pub mod game;
+pub mod replay;
 
 ///
 #[derive(Error, Debug)]
@@ -111,6 +116,8 @@ pub struct JournalWatcher {
     watcher_rx: mpsc::Receiver<()>,
     journal_files: Vec<PathBuf>,
     current_file_index: usize,
+    /// The current file ended with a `Continued` event, so the next part is due any moment
+    continued: bool,
 }
 
 /// Returns the path to the Elite Dangerous journal directory as a `PathBuf`.
@@ -167,6 +174,7 @@ impl JournalWatcher {
             watcher_rx,
             journal_files,
             current_file_index,
+            continued: false,
         };
 
         watcher.spawn_watcher()?;
@@ -200,8 +208,9 @@ impl JournalWatcher {
     ///
     /// This function operates within an event loop, continuously monitoring for updates to journal files.
     /// - If a journal file is being actively read and contains a new line, the line is parsed as a JSON-encoded `JournalEvent`.
-    /// - If the JSON deserialization succeeds, a `Message::JournalEvent` is returned.
-    /// - If deserialization fails, appropriate error messages are logged. If the deserialization error indicates an unknown variant, a more detailed message is logged.
+    /// - If the JSON deserialization succeeds, a `Message::JournalEvent` is returned. Events we have
+    ///   no schema for come back as `Event::Unknown`.
+    /// - If deserialization fails, a `Message::JournalUnparsed` is returned instead, and reading carries on.
     ///
     /// The function also handles the following cases:
     /// - End of the currently monitored journal file:
@@ -220,11 +229,23 @@ impl JournalWatcher {
                 if bytes_read > 0 {
                     let line = buffer.as_str();
                     info!("Journal file updated: {}", &line);
-                    let event: Event = serde_json::from_str(line)?;
-                    return Ok(Message::JournalEvent(event));
+                    // a bad line is reported and skipped, rather than ending the live stream
+                    let message = parse_line(file_name(&self.current_journal_path), line);
+                    if matches!(message, Message::JournalEvent(Event::Continued(_))) {
+                        info!("Journal continues in the next part");
+                        self.continued = true;
+                    }
+                    return Ok(message);
                 }
             }
 
+            // The current file is finished: move straight on if it said it continues in another
+            // part, or a later file is already known
+            let has_later = self.current_file_index + 1 < self.journal_files.len();
+            if self.reader.is_some() && (self.continued || has_later) && self.switch_to_next_file()? {
+                continue;
+            }
+
             // Wait for filesystem notification
             self.watcher_rx.recv().await.ok_or(JournalError::Channel)?;
 
@@ -232,37 +253,47 @@ impl JournalWatcher {
             tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
             while self.watcher_rx.try_recv().is_ok() {}
 
-            // Check for new or updated journal files
-            let dir_path = self.base_dir.as_path();
-            let journal_files = get_paths(dir_path)?;
-            if !journal_files.is_empty() {
-                let increased = journal_files.len() > self.journal_files.len();
-                let had_none = self.reader.is_none();
-                if increased {
-                    info!("New journal file detected, switching to the newest file");
-                    self.journal_files = journal_files;
+            if self.reader.is_none() {
+                // We didn't have a reader: open the newest file and seek to the end
+                self.journal_files = get_paths(self.base_dir.as_path())?;
+                if let Some(path) = self.journal_files.last().cloned() {
                     self.current_file_index = self.journal_files.len() - 1;
-                    self.current_journal_path = self.journal_files[self.current_file_index].clone();
-                    let file = OpenOptions::new().read(true).open(&self.current_journal_path)?;
-                    let mut reader = BufReader::new(file);
-                    reader.seek(SeekFrom::Start(0))?;
-                    self.reader = Some(reader);
-                } else if had_none {
-                    // No new files, but we didn't have a reader: reopen newest and seek to end
-                    self.journal_files = journal_files;
-                    self.current_file_index = self.journal_files.len() - 1;
-                    self.current_journal_path = self.journal_files[self.current_file_index].clone();
+                    self.current_journal_path = path;
                     let file = OpenOptions::new().read(true).open(&self.current_journal_path)?;
                     let mut reader = BufReader::new(file);
                     reader.seek(SeekFrom::End(0))?;
                     self.reader = Some(reader);
-                } else {
-                    // Same set of files: the current one may have been appended.
-                    self.journal_files = journal_files;
                 }
+            } else {
+                // Either the current file was appended, or the game has started the next one
+                self.switch_to_next_file()?;
             }
         }
     }
+
+    /// Re-lists the journal files and opens the one after the current file, in the order the game
+    /// wrote them. Returns false if there isn't one yet.
+    fn switch_to_next_file(&mut self) -> Result<bool, JournalError> {
+        self.journal_files = get_paths(self.base_dir.as_path())?;
+
+        let next = match self.journal_files.iter().position(|p| p == &self.current_journal_path) {
+            Some(index) => {
+                self.current_file_index = index;
+                index + 1
+            }
+            // the current file has gone, so carry on with the newest
+            None => self.journal_files.len().saturating_sub(1),
+        };
+        let Some(path) = self.journal_files.get(next).cloned() else { return Ok(false) };
+
+        info!("Switching to journal file {}", file_name(&path));
+        let file = OpenOptions::new().read(true).open(&path)?;
+        self.reader = Some(BufReader::new(file));
+        self.current_file_index = next;
+        self.current_journal_path = path;
+        self.continued = false;
+        Ok(true)
+    }
 }
 
 ///
@@ -293,16 +324,84 @@ pub fn get_paths(dir: &Path) -> Result<Vec<PathBuf>, JournalError> {
         return Ok(files);
     }
 
-    // Sort files by modified time; if metadata lookup fails for a file, push it to the start
-    files.sort_by_key(|path| {
-        std::fs::metadata(path)
-            .and_then(|m| m.modified())
-            .unwrap_or(SystemTime::UNIX_EPOCH)
-    });
+    // Sort files into the order the game wrote them; modified times can't be trusted once
+    // journals have been copied or synced between machines
+    files.sort_by_cached_key(|path| file_order(path));
 
     Ok(files)
 }
 
+/// When a journal file was started and which part of that session it is, from its name, or
+/// failing that its `Fileheader`, or failing that when it was last modified. File names carry
+/// the local time, so the UTC fallbacks are converted to local time to sort alongside them.
+fn file_order(path: &Path) -> (NaiveDateTime, u64) {
+    parse_file_name(file_name(path))
+        .or_else(|| read_file_header(path))
+        .unwrap_or_else(|| {
+            let modified = std::fs::metadata(path)
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            (DateTime::<Local>::from(modified).naive_local(), 0)
+        })
+}
+
+/// Parses the start time and part number out of a journal file name, either the current
+/// `Journal.2025-07-31T160600.01.log` or the older `Journal.250731160600.01.log`.
+fn parse_file_name(name: &str) -> Option<(NaiveDateTime, u64)> {
+    if !name.starts_with("Journal") {
+        return None;
+    }
+    let (_, rest) = name.split_once('.')?;
+    let (rest, _extension) = rest.rsplit_once('.')?;
+    let (started, part) = rest.rsplit_once('.')?;
+
+    let part = part.parse().ok()?;
+    let started = NaiveDateTime::parse_from_str(started, "%Y-%m-%dT%H%M%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(started, "%y%m%d%H%M%S"))
+        .ok()?;
+    Some((started, part))
+}
+
+/// Reads the timestamp, in local time, and part number from the `Fileheader` on a journal's
+/// first line.
+fn read_file_header(path: &Path) -> Option<(NaiveDateTime, u64)> {
+    let mut line = String::new();
+    BufReader::new(File::open(path).ok()?).read_line(&mut line).ok()?;
+
+    let header: serde_json::Value = serde_json::from_str(&line).ok()?;
+    if header.get("event")?.as_str()? != "Fileheader" {
+        return None;
+    }
+    let started: DateTime<Utc> = header.get("timestamp")?.as_str()?.parse().ok()?;
+    let part = header.get("part").and_then(|p| p.as_u64()).unwrap_or(1);
+    Some((started.with_timezone(&Local).naive_local(), part))
+}
+
+fn file_name(path: &Path) -> &str {
+    path.file_name().and_then(|n| n.to_str()).unwrap_or_default()
+}
+
+/// Parses one journal line (or a whole snapshot file), turning a failure into a message for the
+/// diagnostics pane instead of an error.
+fn parse_line(file: &str, line: &str) -> Message {
+    match serde_json::from_str(line) {
+        Ok(event) => Message::JournalEvent(event),
+        Err(e) => {
+            warn!("Failed to parse {}: {}\nJSON: {}", file, e, line.trim_end());
+            Message::JournalUnparsed(UnparsedLine::new(file, line, &e))
+        }
+    }
+}
+
+/// Parses a whole snapshot file (Status.json, Market.json and so on). Its event isn't a line of
+/// the journal, so it comes out as a `Message::SnapshotEvent`.
+fn parse_snapshot(file: &str, content: &str) -> Message {
+    match parse_line(file, content) {
+        Message::JournalEvent(event) => Message::SnapshotEvent(event),
+        other => other,
+    }
+}
+
 /// Checks the provided snapshot file for updates and parses its content into a `JournalEvent` if applicable.
 ///
 /// # Arguments
@@ -312,15 +411,16 @@ pub fn get_paths(dir: &Path) -> Result<Vec<PathBuf>, JournalError> {
 ///
 /// # Returns
 ///
-/// * `Result<Option<JournalEvent>, JournalError>`:
-///   - `Ok(Some(JournalEvent))`: If the file was updated, and a valid `JournalEvent` was created after parsing its content.
+/// * `Result<Option<Message>, JournalError>`:
+///   - `Ok(Some(Message))`: If the file was updated: a `Message::SnapshotEvent`, or a `Message::JournalUnparsed`
+///     if its content couldn't be parsed.
 ///   - `Ok(None)`: If the file does not exist, is empty, or has not been modified since the last check.
-///   - `Err(JournalError)`: If an error occurred during file operations, parsing, or metadata retrieval.
+///   - `Err(JournalError)`: If an error occurred during file operations or metadata retrieval.
 ///
 /// # Behavior
 ///
 /// 1. The function checks whether the file is specified in `
-fn check_snapshot_file(file_details: &mut FileDetails) -> Result<Option<Event>, JournalError> {
+fn check_snapshot_file(file_details: &mut FileDetails) -> Result<Option<Message>, JournalError> {
     // Check if the file exists
     if !file_details.path.exists() {
         return Ok(None);
@@ -345,8 +445,7 @@ fn check_snapshot_file(file_details: &mut FileDetails) -> Result<Option<Event>,
             &file_details.path.file_name().unwrap_or_default()
         );
 
-        let event: Event = serde_json::from_str(&line)?;
-        return Ok(Some(event));
+        return Ok(Some(parse_snapshot(file_name(&file_details.path), &line)));
     }
 
     Ok(None)
@@ -434,12 +533,13 @@ impl SnapshotWatcher {
     ///
     /// This method continuously listens for updates from the given `watcher_rx` channel and evaluates the
     /// snapshot file for events using the `check_snapshot_file` function. When a journal event is successfully
-    /// found, it constructs and returns a `Message::JournalEvent` containing the event data.
+    /// found, it constructs and returns a `Message::SnapshotEvent` containing the event data.
     ///
     /// # Behavior
     /// - Listens indefinitely for events from the `watcher_rx` channel.
     /// - Invokes `check_snapshot_file` to analyze the state of the snapshot file.
-    /// - If an event is found, the method returns `Message::JournalEvent` wrapping the event.
+    /// - If the file changed, the method returns `Message::SnapshotEvent` wrapping the event, or
+    ///   `Message::JournalUnparsed` if it couldn't be parsed.
     /// - If no event is found (`Ok(None)`), the loop continues.
     /// - Logs an error if `check_snapshot_file` encounters an error.
     ///
@@ -452,8 +552,8 @@ impl SnapshotWatcher {
             // Drain any pending signals to avoid immediate re-triggering
             while self.watcher_rx.try_recv().is_ok() {}
 
-            if let Some(event) = check_snapshot_file(&mut self.file)? {
-                return Ok(Message::JournalEvent(event));
+            if let Some(message) = check_snapshot_file(&mut self.file)? {
+                return Ok(message);
             }
         }
     }
@@ -467,6 +567,7 @@ impl SnapshotWatcher {
 /// * `dir` - A `PathBuf` that specifies the directory from which
 /// historical data will be loaded. This field points to the location
 /// containing relevant files or resources associated with the data.
+/// * `resume_from` - An optional checkpoint position; journal lines up to it are skipped.
 ///
 /// # Example
 ///
@@ -474,60 +575,59 @@ impl SnapshotWatcher {
 /// use std::path::PathBuf;
 /// use your_crate::HistoryLoader;
 ///
-/// let loader = HistoryLoader {
-///     dir: PathBuf::from("/path/to/history"),
-/// };
+/// let loader = HistoryLoader::with_dir(PathBuf::from("/path/to/history"));
+/// for message in loader.messages()? {
+///     // ...
+/// }
 /// ```
-///
-/// The `HistoryLoader` struct can be extended with additional methods
-/// or functionality to retrieve and process files from the specified directory.
 pub struct HistoryLoader {
     dir: PathBuf,
+    resume_from: Option<checkpoint::Position>,
+}
+
+/// How far through the journal history the loader has got, for the loading screen.
+#[derive(Debug, Clone)]
+pub struct LoadProgress {
+    pub file_name: Box<str>,
+    pub files_done: usize,
+    pub file_count: usize,
+}
+
+/// A journal line that couldn't be parsed as an event, kept for the diagnostics pane.
+#[derive(Debug, Clone)]
+pub struct UnparsedLine {
+    pub file: Box<str>,
+    pub error: Box<str>,
+    pub line: Box<str>,
+}
+
+impl UnparsedLine {
+    /// Longest part of the line that's kept; some events (e.g. `Loadout`) run to kilobytes
+    const MAX_LINE: usize = 500;
+
+    fn new(file: &str, line: &str, error: &serde_json::Error) -> Self {
+        let line = line.trim();
+        let end = line.char_indices().nth(Self::MAX_LINE).map_or(line.len(), |(i, _)| i);
+        Self { file: file.into(), error: error.to_string().into(), line: line[..end].into() }
+    }
 }
 
 impl HistoryLoader {
     
     /// Create a history loader for a specific directory
     pub fn with_dir(dir: PathBuf) -> Self {
-        Self { dir }
+        Self { dir, resume_from: None }
     }
 
-    /// Reads all journal events from files located in the directory specified by `self.dir`.
-    ///
-    /// This function iterates through all the files in the journal directory, reads each file line-by-line,
-    /// and attempts to deserialize each non-empty line into a `JournalEvent` object using `serde_json`.
-    /// Successfully deserialized events are collected into a vector, which is returned upon completion.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(Vec<JournalEvent>)` - A vector containing all successfully read and deserialized journal events.
-    /// * `Err(JournalError)` - An error occurred while retrieving journal paths, opening files,
-    ///   reading lines, or deserializing a line into
-    fn read_all_journal_events(&self) -> Result<Vec<Event>, JournalError> {
-        let mut events = Vec::new();
-        let files = get_paths(&self.dir)?;
-        for path in files {
-            let file = OpenOptions::new().read(true).open(&path)?;
-            let mut reader = BufReader::new(file);
-            let mut line = String::new();
-            loop {
-                line.clear();
-                let n = reader.read_line(&mut line)?;
-                if n == 0 { break; }
-                if line.trim().is_empty() { continue; }
-                match serde_json::from_str(&line) {
-                    Ok(ev) => events.push(ev),
-                    Err(e) => {
-                        error!("Failed to parse JSON: {}\nJSON: {}", e, &line);
-                    }
-                }
-            }
-        }
-        Ok(events)
+    /// Skip everything up to a checkpointed position, only replaying lines written after it.
+    pub fn resume_from(mut self, position: Option<checkpoint::Position>) -> Self {
+        self.resume_from = position;
+        self
     }
+
     ///
     /// Reads snapshot event files from a specified directory and parses
-    fn read_snapshot_events(&self) -> Result<Vec<Event>, JournalError> {
+    fn read_snapshot_events(dir: &Path) -> Result<Vec<Message>, JournalError> {
         let names = [
             "Status.json",
             "Backpack.json",
@@ -538,46 +638,358 @@ impl HistoryLoader {
         ];
         let mut events = Vec::new();
         for name in names {
-            let path = self.dir.join(name);
+            let path = dir.join(name);
             if !path.exists() { continue; }
             let content = std::fs::read_to_string(&path)?;
             if content.trim().is_empty() { continue; }
-            let ev: Event = serde_json::from_str(&content)?;
-            events.push(ev);
+            events.push(parse_snapshot(name, &content));
         }
         Ok(events)
     }
 
-    /// Loads all messages by aggregating journal events and snapshot events, ensuring that
-    /// snapshots are applied last to reflect the most up-to-date state.
+    /// Returns a lazy iterator over the whole history. Journal files are parsed concurrently on a
+    /// small pool of worker threads, a bounded number of files ahead of what has been consumed, and
+    /// handed back strictly in file order so memory use doesn't grow with the size of the journal directory.
     ///
-    /// This function combines events retrieved from the journal and snapshots, processes
-    /// them into `Message` objects, and appends a final `Message::JournalLoaded` to
-    /// indicate the completion of the operation. Any errors encountered during the retrieval
-    /// of journal or snapshot events are logged, and an empty event list is substituted.
-    ///
-    /// # Returns
-    ///
-    /// A `Vec<Message>` containing:
-    /// - Transformed journal events as `Message::JournalEvent`s.
-    /// - Transformed snapshot events as `Message::JournalEvent`s.
-    /// - A final `Message::JournalLoaded` to signify successful loading.
+    /// The iterator yields, in order:
+    /// - A `Message::JournalProgress` as each journal file is opened.
+    /// - A `Message::JournalEvent` for every line in it.
+    /// - The snapshot events (Status.json and friends), applied last to reflect the current state.
+    /// - A final `Message::JournalLoaded` carrying the position reached, to signify the end. It
+    ///   carries no position if a file couldn't be read, so no checkpoint is saved past it.
     ///
     /// # Error Handling
     ///
-    /// If an error occurs when fetching journal or snapshot events, it logs the error and
-    ///
-    pub fn load_messages(&self) -> Result<Vec<Message>, JournalError> {
-        let journal_events = self.read_all_journal_events()?;
-        let snapshot_events = self.read_snapshot_events()?;
-
-        let mut msgs: Vec<Message> = journal_events
-            .into_iter()
-            .map(Message::JournalEvent)
-            .collect();
-        // Apply snapshots last to reflect the current state
-        msgs.extend(snapshot_events.into_iter().map(Message::JournalEvent));
-        msgs.push(Message::JournalLoaded);
-        Ok(msgs)
+    /// Only listing the journal directory can fail up front. Files that can't be read later on
+    /// are logged and skipped, and bad lines are logged and dropped.
+    pub fn messages(&self) -> Result<HistoryMessages, JournalError> {
+        let mut files = get_paths(&self.dir)?;
+
+        if let Some(resume) = &self.resume_from {
+            if let Some(index) = files.iter().position(|p| file_name(p) == resume.file.as_ref()) {
+                files.drain(..index);
+            }
+        }
+
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+
+        Ok(HistoryMessages {
+            dir: self.dir.clone(),
+            file_count: files.len(),
+            files: files.into_iter(),
+            files_done: 0,
+            resume_from: self.resume_from.clone(),
+            parsers: Some(spawn_parsers(workers)),
+            in_flight: VecDeque::new(),
+            max_in_flight: workers * 2,
+            current: None,
+            position: self.resume_from.clone(),
+            stalled: false,
+            tail: None,
+        })
+    }
+}
+
+/// Iterator returned by [`HistoryLoader::messages`].
+pub struct HistoryMessages {
+    dir: PathBuf,
+    files: std::vec::IntoIter<PathBuf>,
+    file_count: usize,
+    files_done: usize,
+    resume_from: Option<checkpoint::Position>,
+    parsers: Option<crossbeam_channel::Sender<ParseJob>>,
+    in_flight: VecDeque<crossbeam_channel::Receiver<Option<ParsedFile>>>,
+    max_in_flight: usize,
+    current: Option<std::vec::IntoIter<Message>>,
+    position: Option<checkpoint::Position>,
+    /// A file couldn't be read to the end, so the later ones have been applied over a gap and
+    /// the state mustn't be checkpointed
+    stalled: bool,
+    tail: Option<std::vec::IntoIter<Message>>,
+}
+
+impl HistoryMessages {
+
+    /// Hands files to the parser pool until enough are queued up ahead of the consumer.
+    fn schedule(&mut self) {
+        let Some(parsers) = &self.parsers else { return };
+
+        while self.in_flight.len() < self.max_in_flight {
+            let Some(path) = self.files.next() else { break };
+
+            let name: Box<str> = file_name(&path).into();
+            let offset = match &self.resume_from {
+                Some(resume) if resume.file == name => resume.offset,
+                _ => 0,
+            };
+
+            let (result, receiver) = crossbeam_channel::bounded(1);
+            if parsers.send(ParseJob { path, name, offset, result }).is_err() {
+                break;
+            }
+            self.in_flight.push_back(receiver);
+        }
+    }
+}
+
+impl Iterator for HistoryMessages {
+    type Item = Message;
+
+    fn next(&mut self) -> Option<Message> {
+        loop {
+            if let Some(messages) = &mut self.current {
+                if let Some(message) = messages.next() {
+                    return Some(message);
+                }
+                self.current = None;
+                self.files_done += 1;
+            }
+
+            if let Some(tail) = &mut self.tail {
+                return tail.next();
+            }
+
+            self.schedule();
+
+            let Some(receiver) = self.in_flight.pop_front() else {
+                // dropping the job sender lets the worker threads finish
+                self.parsers = None;
+
+                let mut tail: Vec<Message> = HistoryLoader::read_snapshot_events(&self.dir)
+                    .unwrap_or_else(|e| {
+                        error!("Failed to read snapshot files: {}", e);
+                        Vec::new()
+                    });
+                if self.stalled {
+                    warn!("Not checkpointing the state: part of the journal couldn't be read");
+                }
+                tail.push(Message::JournalLoaded(self.position.clone().filter(|_| !self.stalled)));
+                self.tail = Some(tail.into_iter());
+                continue;
+            };
+
+            let Ok(Some(parsed)) = receiver.recv() else {
+                self.stalled = true;
+                self.files_done += 1;
+                continue;
+            };
+
+            self.position = Some(checkpoint::Position { file: parsed.name.clone(), offset: parsed.offset });
+            self.stalled |= parsed.failed;
+            self.current = Some(parsed.messages.into_iter());
+
+            return Some(Message::JournalProgress(LoadProgress {
+                file_name: parsed.name,
+                files_done: self.files_done,
+                file_count: self.file_count,
+            }));
+        }
+    }
+}
+
+struct ParseJob {
+    path: PathBuf,
+    name: Box<str>,
+    offset: u64,
+    result: crossbeam_channel::Sender<Option<ParsedFile>>,
+}
+
+/// A message for every line in one journal file, plus the offset just past the last complete line.
+struct ParsedFile {
+    name: Box<str>,
+    offset: u64,
+    messages: Vec<Message>,
+    /// Reading stopped at an error rather than the end of the file
+    failed: bool,
+}
+
+/// Starts worker threads that parse whole journal files. They exit once the returned sender is dropped.
+fn spawn_parsers(count: usize) -> crossbeam_channel::Sender<ParseJob> {
+    let (jobs, receiver) = crossbeam_channel::unbounded::<ParseJob>();
+
+    for _ in 0..count {
+        let receiver = receiver.clone();
+        std::thread::spawn(move || {
+            for job in receiver {
+                let parsed = match JournalFile::open(&job.path, job.name, job.offset) {
+                    Ok(mut file) => {
+                        let messages = std::iter::from_fn(|| file.next_message()).collect();
+                        Some(ParsedFile { name: file.name, offset: file.offset, messages, failed: file.failed })
+                    }
+                    Err(e) => {
+                        error!("Failed to open journal file {}: {}", job.path.display(), e);
+                        None
+                    }
+                };
+                let _ = job.result.send(parsed);
+            }
+        });
+    }
+
+    jobs
+}
+
+/// A journal file being read line by line, tracking the offset of the last complete line.
+struct JournalFile {
+    name: Box<str>,
+    reader: BufReader<File>,
+    offset: u64,
+    line: Vec<u8>,
+    failed: bool,
+}
+
+impl JournalFile {
+
+    fn open(path: &Path, name: Box<str>, offset: u64) -> Result<Self, JournalError> {
+        let file = OpenOptions::new().read(true).open(path)?;
+        let mut reader = BufReader::new(file);
+        reader.seek(SeekFrom::Start(offset))?;
+        Ok(Self { name, reader, offset, line: Vec::new(), failed: false })
+    }
+
+    fn next_message(&mut self) -> Option<Message> {
+        loop {
+            self.line.clear();
+            let n = match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(n) => n,
+                Err(e) => {
+                    error!("Failed to read journal file {}: {}", self.name, e);
+                    self.failed = true;
+                    return None;
+                }
+            };
+            // stop at a partially written line; it will be picked up next time
+            if n == 0 || self.line.last() != Some(&b'\n') { return None; }
+            self.offset += n as u64;
+            // a line that isn't valid UTF-8 is reported as unparsed like any other bad line
+            let line = String::from_utf8_lossy(&self.line);
+            if line.trim().is_empty() { continue; }
+            return Some(parse_line(&self.name, &line));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, min, sec).unwrap()
+    }
+
+    #[test]
+    fn file_names_give_start_time_and_part() {
+        assert_eq!(parse_file_name("Journal.2025-07-31T160600.01.log"), Some((at(2025, 7, 31, 16, 6, 0), 1)));
+        assert_eq!(parse_file_name("Journal.2025-07-31T160600.02.log"), Some((at(2025, 7, 31, 16, 6, 0), 2)));
+        assert_eq!(parse_file_name("Journal.170521103050.01.log"), Some((at(2017, 5, 21, 10, 30, 50), 1)));
+        assert_eq!(parse_file_name("JournalBeta.2025-07-31T160600.01.log"), Some((at(2025, 7, 31, 16, 6, 0), 1)));
+        assert_eq!(parse_file_name("Journal copy.log"), None);
+        assert_eq!(parse_file_name("notes.log"), None);
+    }
+
+    #[test]
+    fn files_are_ordered_by_name_then_part() {
+        let dir = std::env::temp_dir().join(format!("elite-assist-journal-order-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let names = [
+            "Journal.2025-07-31T160600.02.log",
+            "Journal.2025-07-31T160600.10.log",
+            "Journal.170521103050.01.log",
+            "Journal.2025-08-01T090000.01.log",
+            "Journal.2025-07-31T160600.01.log",
+            "renamed.log",
+        ];
+        for name in names {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        // the header is in UTC while the names are local, so it has to sort by local time
+        let started = at(2025, 7, 31, 18, 0, 0).and_local_timezone(Local).earliest().unwrap().with_timezone(&Utc);
+        std::fs::write(
+            dir.join("renamed.log"),
+            format!("{{ \"timestamp\":\"{}\", \"event\":\"Fileheader\", \"part\":3 }}\n", started.format("%Y-%m-%dT%H:%M:%SZ")),
+        ).unwrap();
+
+        let ordered: Vec<String> = get_paths(&dir).unwrap().iter().map(|p| file_name(p).to_string()).collect();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(ordered, [
+            "Journal.170521103050.01.log",
+            "Journal.2025-07-31T160600.01.log",
+            "Journal.2025-07-31T160600.02.log",
+            "Journal.2025-07-31T160600.10.log",
+            "renamed.log",
+            "Journal.2025-08-01T090000.01.log",
+        ]);
+    }
+
+    fn history_of(dir: &Path) -> Vec<Message> {
+        HistoryLoader::with_dir(dir.to_path_buf()).messages().unwrap().collect()
+    }
+
+    #[test]
+    fn undecodable_lines_are_reported_and_reading_carries_on() {
+        let dir = std::env::temp_dir().join(format!("elite-assist-journal-undecodable-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let line = "{ \"timestamp\":\"2025-07-31T16:06:00Z\", \"event\":\"Music\", \"MusicTrack\":\"MainMenu\" }\n";
+
+        let mut broken = line.as_bytes().to_vec();
+        broken.extend_from_slice(b"\xff\xfe not utf-8\n");
+        broken.extend_from_slice(line.as_bytes());
+        std::fs::write(dir.join("Journal.2025-07-31T160600.01.log"), broken).unwrap();
+        std::fs::write(dir.join("Journal.2025-07-31T170000.01.log"), line).unwrap();
+
+        let messages = history_of(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(messages.iter().filter(|m| matches!(m, Message::JournalUnparsed(_))).count(), 1);
+        assert_eq!(messages.iter().filter(|m| matches!(m, Message::JournalEvent(_))).count(), 3);
+        assert!(matches!(messages.last(), Some(Message::JournalLoaded(Some(position)))
+            if position.file.as_ref() == "Journal.2025-07-31T170000.01.log" && position.offset == line.len() as u64));
+    }
+
+    #[test]
+    fn history_that_failed_to_read_isnt_checkpointed() {
+        let dir = std::env::temp_dir().join(format!("elite-assist-journal-failed-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let line = "{ \"timestamp\":\"2025-07-31T17:00:00Z\", \"event\":\"Music\", \"MusicTrack\":\"MainMenu\" }\n";
+
+        // a directory with a journal's name can be listed but not read
+        std::fs::create_dir_all(dir.join("Journal.2025-07-31T160600.01.log")).unwrap();
+        std::fs::write(dir.join("Journal.2025-07-31T170000.01.log"), line).unwrap();
+
+        let messages = history_of(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // the later file is still applied, so a checkpoint pointing before it would replay it onto
+        // a state that already has it; with no position nothing is saved
+        assert!(messages.iter().any(|m| matches!(m, Message::JournalEvent(_))));
+        assert!(matches!(messages.last(), Some(Message::JournalLoaded(None))));
+    }
+
+    #[tokio::test]
+    async fn watcher_follows_a_journal_into_its_next_part() {
+        let dir = std::env::temp_dir().join(format!("elite-assist-journal-continued-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let first = dir.join("Journal.2025-07-31T160600.01.log");
+        std::fs::write(&first, "{ \"timestamp\":\"2025-07-31T16:06:00Z\", \"event\":\"Music\", \"MusicTrack\":\"MainMenu\" }\n").unwrap();
+
+        let mut watcher = JournalWatcher::new_with_dir(dir.clone()).unwrap();
+
+        // the game ends the first part and starts the second
+        let mut file = OpenOptions::new().append(true).open(&first).unwrap();
+        std::io::Write::write_all(&mut file, b"{ \"timestamp\":\"2025-07-31T18:00:00Z\", \"event\":\"Continued\", \"Part\":2 }\n").unwrap();
+        drop(file);
+        let continued = watcher.next().await.unwrap();
+
+        std::fs::write(
+            dir.join("Journal.2025-07-31T160600.02.log"),
+            "{ \"timestamp\":\"2025-07-31T18:00:01Z\", \"event\":\"Music\", \"MusicTrack\":\"Supercruise\" }\n",
+        ).unwrap();
+        let next = tokio::time::timeout(std::time::Duration::from_secs(5), watcher.next()).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(continued, Message::JournalEvent(Event::Continued(_))));
+        assert!(matches!(next, Ok(Ok(Message::JournalEvent(Event::Music(_))))));
     }
 }
@@ -0,0 +1,184 @@
+//! A serialized copy of `State` saved once the journal history has been replayed, so the next
+//! launch only has to replay lines written after it.
+
+use crate::config;
+use crate::journal::{self, event, JournalError};
+use crate::state::State;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name for the persisted state checkpoint, in the app's data directory
+const CHECKPOINT_FILE: &str = "EliteAssist.checkpoint.json";
+
+/// Bump this whenever `State` (or anything it holds) changes how it serializes, so checkpoints
+/// written by an older layout are replayed from scratch instead of half-loaded.
+const STATE_VERSION: u32 = 4;
+
+/// How far through the journal files a checkpoint got: a journal file name and the byte offset
+/// just past the last complete line that was applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Position {
+    pub file: Box<str>,
+    pub offset: u64,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    app_version: Box<str>,
+    schema: Box<str>,
+    state_version: u32,
+    journal_dir: Box<str>,
+    position: Position,
+}
+
+#[derive(Serialize)]
+struct CheckpointRef<'a> {
+    app_version: &'a str,
+    schema: &'a str,
+    state_version: u32,
+    journal_dir: &'a str,
+    position: &'a Position,
+    state: &'a State,
+}
+
+#[derive(Deserialize)]
+struct Checkpoint {
+    state: State,
+}
+
+/// Writes the current state to disk, keyed by the journal directory and position it reflects.
+pub fn save(state: &State) -> Result<(), JournalError> {
+    let journal_dir = journal::get_directory()?;
+    let dir = config::data_dir();
+    if !dir.as_os_str().is_empty() {
+        fs::create_dir_all(&dir)?;
+    }
+    write(&dir.join(CHECKPOINT_FILE), &journal_dir, state)
+}
+
+/// Loads the saved state if it was written by this version of the app from the same journal
+/// directory, and the journal file it points into still holds at least as many bytes as were read.
+pub fn load(journal_dir: &Path) -> Option<State> {
+    read(&config::data_dir().join(CHECKPOINT_FILE), journal_dir)
+}
+
+fn write(path: &Path, journal_dir: &Path, state: &State) -> Result<(), JournalError> {
+    let Some(position) = &state.journal_position else { return Ok(()) };
+
+    let journal_dir = canonical(journal_dir).to_string_lossy().into_owned();
+    let checkpoint = CheckpointRef {
+        app_version: env!("CARGO_PKG_VERSION"),
+        schema: event::SCHEMA_HASH,
+        state_version: STATE_VERSION,
+        journal_dir: &journal_dir,
+        position,
+        state,
+    };
+
+    let json = serde_json::to_string(&checkpoint)?;
+    fs::write(path, json)?;
+    info!("Saved state checkpoint at {} offset {}", position.file, position.offset);
+    Ok(())
+}
+
+fn read(path: &Path, journal_dir: &Path) -> Option<State> {
+    let data = fs::read_to_string(path).ok()?;
+
+    let header: Header = serde_json::from_str(&data)
+        .inspect_err(|e| warn!("Ignoring unreadable state checkpoint: {}", e))
+        .ok()?;
+
+    if header.app_version.as_ref() != env!("CARGO_PKG_VERSION")
+        || header.schema.as_ref() != event::SCHEMA_HASH
+        || header.state_version != STATE_VERSION
+    {
+        info!("State checkpoint is from a different version, replaying the full journal history");
+        return None;
+    }
+
+    if Path::new(header.journal_dir.as_ref()) != canonical(journal_dir) {
+        info!("State checkpoint was built from {}, replaying the full journal history", header.journal_dir);
+        return None;
+    }
+
+    let journal_len = fs::metadata(journal_dir.join(header.position.file.as_ref())).ok()?.len();
+    if journal_len < header.position.offset {
+        warn!("Journal file {} is shorter than the state checkpoint, ignoring it", header.position.file);
+        return None;
+    }
+
+    let checkpoint: Checkpoint = serde_json::from_str(&data)
+        .inspect_err(|e| warn!("Ignoring incompatible state checkpoint: {}", e))
+        .ok()?;
+
+    let mut state = checkpoint.state;
+    state.journal_position = Some(header.position);
+    Some(state)
+}
+
+/// The same directory can be written several ways, so compare them resolved where possible.
+fn canonical(dir: &Path) -> PathBuf {
+    fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixture {
+        root: PathBuf,
+        journals: PathBuf,
+        checkpoint: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("elite-assist-checkpoint-{}-{}", name, std::process::id()));
+            let journals = root.join("journals");
+            fs::create_dir_all(&journals).unwrap();
+            fs::write(journals.join("Journal.2025-01-01T120000.01.log"), "x".repeat(100)).unwrap();
+            Self { checkpoint: root.join(CHECKPOINT_FILE), root, journals }
+        }
+
+        fn state(offset: u64) -> State {
+            State {
+                commander_name: "Jameson".into(),
+                journal_position: Some(Position { file: "Journal.2025-01-01T120000.01.log".into(), offset }),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    #[test]
+    fn checkpoint_resumes_from_its_position() {
+        let fixture = Fixture::new("round-trip");
+        write(&fixture.checkpoint, &fixture.journals, &Fixture::state(60)).unwrap();
+
+        let state = read(&fixture.checkpoint, &fixture.journals).expect("checkpoint should load");
+        assert_eq!(state.commander_name.as_ref(), "Jameson");
+        assert_eq!(state.journal_position, Some(Position { file: "Journal.2025-01-01T120000.01.log".into(), offset: 60 }));
+    }
+
+    #[test]
+    fn checkpoint_is_ignored_for_other_journals() {
+        let fixture = Fixture::new("mismatch");
+        write(&fixture.checkpoint, &fixture.journals, &Fixture::state(60)).unwrap();
+
+        let other = fixture.root.join("other");
+        fs::create_dir_all(&other).unwrap();
+        fs::write(other.join("Journal.2025-01-01T120000.01.log"), "x".repeat(100)).unwrap();
+        assert!(read(&fixture.checkpoint, &other).is_none());
+
+        // the journal was truncated or replaced since the checkpoint was written
+        write(&fixture.checkpoint, &fixture.journals, &Fixture::state(200)).unwrap();
+        assert!(read(&fixture.checkpoint, &fixture.journals).is_none());
+    }
+}
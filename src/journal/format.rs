@@ -1,6 +1,6 @@
 use chrono::DateTime;
 use chrono::Utc;
-use serde::{self, Deserialize, Deserializer};
+use serde::{self, Deserialize, Deserializer, Serializer};
 
 pub mod date {
     use super::*;
@@ -15,6 +15,14 @@ pub mod date {
             .map(|dt| dt.with_timezone(&Utc))
             .map_err(serde::de::Error::custom)
     }
+
+    // Writes dates back out the way the game does, e.g. 2016-06-10T14:32:03Z
+    pub fn serialize<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&date.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+    }
 }
 
 pub mod optional_date {
@@ -33,6 +41,16 @@ pub mod optional_date {
             None => Ok(None),
         }
     }
+
+    pub fn serialize<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => super::date::serialize(date, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
 }
 
 pub fn prettify_date(date: &DateTime<Utc>) -> Box<str> {
@@ -0,0 +1,50 @@
+//! The game version and expansions the journal was written by, which uploads to EDDN and EDSM
+//! have to include. They're given by the `Fileheader` at the start of each journal and by
+//! `LoadGame`.
+
+use crate::journal::{get_directory, get_paths};
+use serde_json::Value;
+
+/// The game the events came from, as told by `Fileheader` and `LoadGame`.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Game {
+    pub version: Box<str>,
+    pub build: Box<str>,
+    pub horizons: bool,
+    pub odyssey: bool,
+}
+
+impl Game {
+    pub fn observe(&mut self, event: &Value) {
+        if let Some(version) = event.get("gameversion").and_then(Value::as_str) {
+            self.version = version.into();
+        }
+        if let Some(build) = event.get("build").and_then(Value::as_str) {
+            self.build = build.into();
+        }
+        if let Some(horizons) = event.get("Horizons").and_then(Value::as_bool) {
+            self.horizons = horizons;
+        }
+        if let Some(odyssey) = event.get("Odyssey").and_then(Value::as_bool) {
+            self.odyssey = odyssey;
+        }
+    }
+
+    /// Reads the newest journal's startup events, for when we resume partway through it and
+    /// won't see its `Fileheader` again.
+    pub fn from_latest_journal() -> Game {
+        let mut game = Game::default();
+        let Some(path) = get_directory().ok().and_then(|dir| get_paths(&dir).ok()?.pop()) else { return game };
+        let Ok(content) = std::fs::read_to_string(&path) else { return game };
+
+        for line in content.lines() {
+            if !line.contains("\"Fileheader\"") && !line.contains("\"LoadGame\"") {
+                continue;
+            }
+            if let Ok(event) = serde_json::from_str::<Value>(line) {
+                game.observe(&event);
+            }
+        }
+        game
+    }
+}
@@ -0,0 +1,171 @@
+//! Re-emits a recorded journal (a journal directory or a single `.log` file) in order, waiting
+//! between events for the original gap between their timestamps divided by a speed factor.
+//! Playback can be paused and stepped one event at a time from the GUI.
+
+use crate::journal::{Event, HistoryLoader, JournalError};
+use crate::message::Message;
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::{watch, Notify};
+
+/// Gaps longer than this (e.g. between play sessions) are shortened before scaling.
+const MAX_GAP: Duration = Duration::from_secs(30);
+
+static CONTROLS: OnceLock<Controls> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub path: PathBuf,
+    pub speed: f64,
+    pub paused: bool,
+}
+
+/// What the GUI shows about a running replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Status {
+    pub speed: f64,
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Control {
+    Play,
+    Pause,
+    Step,
+}
+
+struct Controls {
+    settings: Settings,
+    paused: watch::Sender<bool>,
+    step: Notify,
+}
+
+/// Switches the app into replay mode. Must be called before the GUI starts.
+pub fn start(settings: Settings) -> Status {
+    let status = Status { speed: settings.speed, paused: settings.paused };
+    let (paused, _) = watch::channel(settings.paused);
+    let _ = CONTROLS.set(Controls { settings, paused, step: Notify::new() });
+    status
+}
+
+pub fn is_active() -> bool {
+    CONTROLS.get().is_some()
+}
+
+pub fn control(control: Control) {
+    let Some(controls) = CONTROLS.get() else { return };
+    match control {
+        Control::Play => { controls.paused.send_replace(false); }
+        Control::Pause => { controls.paused.send_replace(true); }
+        Control::Step => {
+            // stepping only makes sense while paused; a stored permit would skip the next pause
+            if *controls.paused.borrow() { controls.step.notify_one() }
+        }
+    }
+}
+
+/// Loads the recorded events and sends them in order, honouring the speed factor and the
+/// pause/step controls. Runs until the recording ends or the receiver is dropped.
+pub async fn play(sender: tokio::sync::mpsc::Sender<Message>) -> Result<(), JournalError> {
+    let Some(controls) = CONTROLS.get() else { return Ok(()) };
+    let speed = controls.settings.speed;
+    let path = controls.settings.path.clone();
+
+    let events = tokio::task::spawn_blocking(move || load(path))
+        .await
+        .map_err(|e| JournalError::Io(std::io::Error::other(e)))??;
+
+    let mut paused = controls.paused.subscribe();
+    let mut previous: Option<DateTime<Utc>> = None;
+
+    for event in events {
+        let timestamp = event.timestamp();
+
+        let mut wait = Box::pin(tokio::time::sleep(
+            previous.map(|p| delay(p, timestamp, speed)).unwrap_or_default()
+        ));
+
+        loop {
+            if *paused.borrow_and_update() {
+                // hold the event back until it's stepped past or playback resumes
+                tokio::select! {
+                    _ = controls.step.notified() => break,
+                    _ = paused.changed() => {}
+                }
+            } else {
+                tokio::select! {
+                    _ = &mut wait => break,
+                    _ = paused.changed() => {}
+                }
+            }
+        }
+
+        previous = Some(timestamp);
+        if sender.send(Message::JournalEvent(event)).await.is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// How long to wait between two events, given the time between them in the recording.
+pub fn delay(previous: DateTime<Utc>, next: DateTime<Utc>, speed: f64) -> Duration {
+    let gap = (next - previous).to_std().unwrap_or_default().min(MAX_GAP);
+    if speed <= 0.0 {
+        return Duration::ZERO;
+    }
+    gap.div_f64(speed)
+}
+
+fn load(path: PathBuf) -> Result<Vec<Event>, JournalError> {
+    if path.is_dir() {
+        let events = HistoryLoader::with_dir(path)
+            .messages()?
+            .filter_map(|message| match message {
+                Message::JournalEvent(event) | Message::SnapshotEvent(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        return Ok(events);
+    }
+
+    let mut events = Vec::new();
+    for line in BufReader::new(File::open(&path)?).lines() {
+        let line = line?;
+        if line.trim().is_empty() { continue; }
+        match serde_json::from_str(&line) {
+            Ok(event) => events.push(event),
+            Err(e) => warn!("Skipping unreadable line in {}: {}", path.display(), e),
+        }
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(3311, 1, 1, 12, 0, 0).unwrap() + chrono::Duration::seconds(seconds)
+    }
+
+    #[test]
+    fn delays_are_scaled_by_speed() {
+        assert_eq!(delay(at(0), at(10), 1.0), Duration::from_secs(10));
+        assert_eq!(delay(at(0), at(10), 4.0), Duration::from_millis(2500));
+        assert_eq!(delay(at(10), at(0), 1.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn long_gaps_are_capped() {
+        assert_eq!(delay(at(0), at(86_400), 1.0), MAX_GAP);
+        assert_eq!(delay(at(0), at(86_400), 2.0), MAX_GAP / 2);
+    }
+}
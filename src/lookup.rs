@@ -101,6 +101,129 @@ pub static SUIT_MODULE_NAMES: Map<&'static str, &'static str> = phf_map! {
     "weapon_headshotdamage_takada" => "Headshot damage: Kinematic Armaments"
 };
 
+// Vista Genomics base payouts per species. The first footfall bonus is paid on top of these.
+pub static EXOBIOLOGY_VALUES: Map<&'static str, u64> = phf_map! {
+    "Aleoida Arcus" => 7_252_500,
+    "Aleoida Coronamus" => 6_284_600,
+    "Aleoida Gravis" => 12_934_900,
+    "Aleoida Laminiae" => 3_385_200,
+    "Aleoida Spica" => 3_385_200,
+    "Amphora Plant" => 1_628_800,
+    "Bacterium Acies" => 1_000_000,
+    "Bacterium Alcyoneum" => 1_658_500,
+    "Bacterium Aurasus" => 1_000_000,
+    "Bacterium Bullaris" => 1_152_500,
+    "Bacterium Cerbrus" => 1_689_800,
+    "Bacterium Informem" => 8_418_000,
+    "Bacterium Nebulus" => 5_289_900,
+    "Bacterium Omentum" => 4_638_900,
+    "Bacterium Scopulum" => 4_934_500,
+    "Bacterium Tela" => 1_949_000,
+    "Bacterium Verrata" => 3_897_000,
+    "Bacterium Vesicula" => 1_000_000,
+    "Bacterium Volu" => 7_774_700,
+    "Bark Mound" => 1_471_900,
+    "Cactoida Cortexum" => 3_667_600,
+    "Cactoida Lapis" => 2_483_600,
+    "Cactoida Peperatis" => 2_483_600,
+    "Cactoida Pullulanta" => 3_667_600,
+    "Cactoida Vermis" => 16_202_800,
+    "Clypeus Lacrimam" => 8_418_000,
+    "Clypeus Margaritus" => 11_873_200,
+    "Clypeus Speculumi" => 16_202_800,
+    "Concha Aureolas" => 7_774_700,
+    "Concha Biconcavis" => 16_777_600,
+    "Concha Labiata" => 2_352_400,
+    "Concha Renibus" => 4_572_400,
+    "Crystalline Shards" => 1_628_800,
+    "Electricae Pluma" => 6_284_600,
+    "Electricae Radialem" => 6_284_600,
+    "Fonticulua Campestris" => 1_000_000,
+    "Fonticulua Digitos" => 1_804_100,
+    "Fonticulua Fluctus" => 20_000_000,
+    "Fonticulua Lapida" => 3_111_000,
+    "Fonticulua Segmentatus" => 19_010_800,
+    "Fonticulua Upupam" => 5_727_600,
+    "Frutexa Acus" => 7_774_700,
+    "Frutexa Collum" => 1_639_800,
+    "Frutexa Fera" => 1_632_500,
+    "Frutexa Flabellum" => 1_808_900,
+    "Frutexa Flammasis" => 10_326_000,
+    "Frutexa Metallicum" => 1_632_500,
+    "Frutexa Sponsae" => 5_988_000,
+    "Fumerola Aquatis" => 6_284_600,
+    "Fumerola Carbosis" => 6_284_600,
+    "Fumerola Extremus" => 16_202_800,
+    "Fumerola Nitris" => 7_500_900,
+    "Fungoida Bullarum" => 3_703_200,
+    "Fungoida Gelata" => 3_330_300,
+    "Fungoida Setisis" => 1_670_100,
+    "Fungoida Stabitis" => 2_680_300,
+    "Osseus Cornibus" => 1_483_000,
+    "Osseus Discus" => 12_934_900,
+    "Osseus Fractus" => 4_027_800,
+    "Osseus Pellebantus" => 9_739_000,
+    "Osseus Pumice" => 3_156_300,
+    "Osseus Spiralis" => 2_404_700,
+    "Recepta Conditivus" => 14_313_700,
+    "Recepta Deltahedronix" => 16_202_800,
+    "Recepta Umbrux" => 12_934_900,
+    "Sinuous Tubers" => 1_514_500,
+    "Stratum Araneamus" => 2_448_900,
+    "Stratum Cucumisis" => 16_202_800,
+    "Stratum Excutitus" => 2_448_900,
+    "Stratum Frigus" => 2_637_500,
+    "Stratum Laminamus" => 2_788_300,
+    "Stratum Limaxus" => 1_362_000,
+    "Stratum Paleas" => 1_362_000,
+    "Stratum Tectonicas" => 19_010_800,
+    "Tubus Cavas" => 11_873_200,
+    "Tubus Compagibus" => 7_774_700,
+    "Tubus Conifer" => 2_415_500,
+    "Tubus Rosarium" => 2_637_500,
+    "Tubus Sororibus" => 5_727_600,
+    "Tussock Albata" => 3_252_500,
+    "Tussock Capillum" => 7_025_800,
+    "Tussock Caputus" => 3_472_400,
+    "Tussock Catena" => 1_766_600,
+    "Tussock Cultro" => 1_766_600,
+    "Tussock Divisa" => 1_766_600,
+    "Tussock Ignis" => 1_849_000,
+    "Tussock Pennata" => 5_853_800,
+    "Tussock Pennatis" => 1_000_000,
+    "Tussock Propagito" => 1_000_000,
+    "Tussock Serrati" => 4_447_100,
+    "Tussock Stigmasis" => 19_010_800,
+    "Tussock Triticum" => 7_774_700,
+    "Tussock Ventusa" => 3_277_700,
+    "Tussock Virgam" => 14_313_700,
+};
+
+// Minimum distance in metres between samples of the same genus, so they count as separate colonies.
+pub static COLONY_DISTANCES: Map<&'static str, u32> = phf_map! {
+    "Aleoida" => 150,
+    "Amphora Plant" => 100,
+    "Anemone" => 100,
+    "Bacterium" => 500,
+    "Bark Mound" => 100,
+    "Brain Tree" => 100,
+    "Cactoida" => 300,
+    "Clypeus" => 150,
+    "Concha" => 150,
+    "Crystalline Shards" => 100,
+    "Electricae" => 1000,
+    "Fonticulua" => 500,
+    "Frutexa" => 150,
+    "Fumerola" => 100,
+    "Fungoida" => 300,
+    "Osseus" => 800,
+    "Recepta" => 150,
+    "Sinuous Tubers" => 100,
+    "Stratum" => 500,
+    "Tubus" => 800,
+    "Tussock" => 200,
+};
+
 pub fn locations_for_material(name: &str) -> Vec<&str> {
     get_generated_items(&MATERIAL_LOCATIONS_MAP, MATERIAL_LOCATION_LISTS, name)
 }
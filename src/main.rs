@@ -3,10 +3,13 @@
 use iced::Size;
 use iced::Task;
 use crate::gui::Gui;
+use crate::message::Message;
 use crate::subscription::subscription;
 use crate::theme::theme;
 pub use util::*;
 
+mod api;
+mod cli;
 mod gui;
 mod journal;
 mod state;
@@ -16,20 +19,63 @@ mod image;
 mod font;
 mod edsm;
 mod ardent;
+mod cache;
+mod eddn;
+mod edsm_upload;
+mod request;
 mod lookup;
 mod query;
 mod config;
 mod message;
 mod util;
 
+#[cfg(test)]
+mod fixture_server;
+
 fn main() {
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let headless = args.first().is_some_and(|a| a == "report");
+
     let mut clog = colog::default_builder();
-    clog.filter(None, log::LevelFilter::Info);
+    clog.filter(None, if headless { log::LevelFilter::Warn } else { log::LevelFilter::Info });
     clog.init();
 
+    if headless {
+        std::process::exit(cli::run(&args[1..]));
+    }
+
+    let replay = match cli::parse_replay(&args) {
+        Ok(replay) => replay.map(journal::replay::start),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    let settings = config::Settings::load();
+    if let Some(api) = settings.as_ref().and_then(|s| s.api.as_ref()) {
+        api::start(api);
+    }
+    if let Some(eddn) = settings.as_ref().and_then(|s| s.eddn.as_ref()) {
+        eddn::start(eddn);
+    }
+    if let Some(edsm) = settings.as_ref().and_then(|s| s.edsm.as_ref()) {
+        edsm_upload::start(edsm);
+    }
+
+    // A replay starts from a blank state and skips the history, so nothing is checkpointed
+    let boot = move || match &replay {
+        Some(status) => {
+            let mut state = crate::state::State::default();
+            state.replay = Some(status.clone());
+            (state, Task::done(Message::JournalLoaded(None)))
+        }
+        None => (crate::state::State::restore(), Task::none()),
+    };
+
     // Run the Iced application
-    let _ = iced::application(|| (crate::state::State::default(), Task::none()), Gui::update, Gui::view)
+    let _ = iced::application(boot, Gui::update, Gui::view)
         .title("EliteAssist")
         .font(font::bytes::EUROSTILE)
         .font(font::bytes::EURO_CAPS)
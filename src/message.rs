@@ -1,10 +1,15 @@
+use crate::api;
+use crate::eddn;
+use crate::edsm_upload;
 use crate::gui::pane;
-use crate::journal::Event;
+use crate::journal::{checkpoint, Event, LoadProgress, UnparsedLine};
 use crate::query as query_api;
 use crate::state::State;
 use chrono::Utc;
 use iced::Task;
 
+#[cfg(test)]
+mod test;
 mod gui;
 mod query;
 mod journal;
@@ -17,7 +22,13 @@ pub enum Message {
     Gui(Gui),
     Query(Query),
     JournalEvent(Event),
-    JournalLoaded,
+    /// An event read from a snapshot file such as Status.json rather than a journal line
+    SnapshotEvent(Event),
+    JournalUnparsed(UnparsedLine),
+    JournalProgress(LoadProgress),
+    JournalLoaded(Option<checkpoint::Position>),
+    /// An API client wants the state snapshot rebuilt
+    ApiStateRequested,
     Empty,
 }
 
@@ -27,21 +38,63 @@ impl Message {
 
             Message::Gui(gui) => gui.update(state),
 
-            Message::Query(q) => q.update(state),
+            Message::Query(q) => {
+                let task = q.update(state);
+                if state.journal_loaded { api::state_changed() }
+                task
+            }
 
-            Message::JournalEvent(event) => event.update(state),
+            Message::JournalEvent(event) => {
+                // only journal lines count towards the upload position, so it matches on replay
+                edsm_upload::publish(&event, state);
+                apply_event(state, event)
+            }
 
-            Message::JournalLoaded => journal_loaded(state),
+            Message::SnapshotEvent(event) => apply_event(state, event),
+
+            Message::JournalUnparsed(line) => {
+                state.diagnostics.unparsed(line);
+                Task::none()
+            }
+
+            Message::JournalProgress(progress) => {
+                state.load_progress = Some(progress);
+                Task::none()
+            }
+
+            Message::JournalLoaded(position) => journal_loaded(state, position),
+
+            Message::ApiStateRequested => {
+                api::publish_state(state);
+                Task::none()
+            }
 
             Message::Empty => Task::none(),
         }
     }
 }
 
-fn journal_loaded(state: &mut State) ->  Task<Message> {
+fn apply_event(state: &mut State, event: Event) -> Task<Message> {
+    eddn::publish(&event, state);
+    if !state.journal_loaded {
+        return event.update(state);
+    }
+    api::publish_event(&event);
+    let task = event.update(state);
+    api::state_changed();
+    task
+}
+
+fn journal_loaded(state: &mut State, position: Option<checkpoint::Position>) ->  Task<Message> {
     
     state.journal_loaded = true;
+    state.load_progress = None;
+    state.journal_position = position;
+    if let Err(e) = checkpoint::save(state) {
+        log::error!("Failed to save state checkpoint: {}", e);
+    }
     if state.layout.current_panes.is_none() { pane::load(&mut state.layout) }
+    api::state_changed();
 
     // some missions could have expired while we were away.
     let expired_mission_ids: Vec<_> = state.missions.iter()
@@ -52,7 +105,5 @@ fn journal_loaded(state: &mut State) ->  Task<Message> {
     state.trim_nav_route(state.location.system_address);
     state.missions.retain(|m| !expired_mission_ids.contains(&m.mission_id));
 
-    query_api::system(
-        state.location.system_name.as_ref(),
-        state.ship_loadout.max_jump_range)
+    query_api::system(state)
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 use crate::gui::pane;
 use crate::gui::screen;
+use crate::journal::replay;
 use crate::message::Message;
+use crate::query;
+use crate::state::queries::Source;
 use crate::state::*;
 use iced::widget::pane_grid;
 use iced::window;
@@ -35,6 +38,16 @@ pub enum Gui {
     JournalDirChosen(Option<std::path::PathBuf>),
 
     UpdateMessageLimit(u16),
+
+    // Query the given sources again for the current system
+    RetryQueries(&'static [Source]),
+
+    // Journal replay controls
+    Replay(replay::Control),
+
+    // Surface navigation
+    SelectSurfaceTarget(Option<surface::Target>),
+    BookmarkSurfacePosition,
 }
 
 impl Gui {
@@ -128,6 +141,23 @@ impl Gui {
                 state.layout.show_messages_days_limit = days;
                 let _ = crate::config::Settings::save_from_state(&state.layout);
             }
+
+            RetryQueries(sources) => return query::sources(state, sources),
+
+            SelectSurfaceTarget(target) => state.surface.target = target,
+
+            BookmarkSurfacePosition => state.surface.bookmark(&state.status),
+
+            Replay(control) => {
+                if let Some(status) = &mut state.replay {
+                    match control {
+                        replay::Control::Play => status.paused = false,
+                        replay::Control::Pause => status.paused = true,
+                        replay::Control::Step => {}
+                    }
+                    replay::control(control);
+                }
+            }
         }
 
         Task::none()
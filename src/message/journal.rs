@@ -20,31 +20,123 @@ impl journal::Event {
             UseConsumable(_) => {}
 
             // CARGO
-            Cargo(_) => {}
-            CargoTransfer(_) => {}
-            CargoDepot(_) => {}
-            CollectCargo(_) => {}
-            EjectCargo(_) => {}
+            Cargo(e) => {
+                // the journal copy of this event has no inventory, only Cargo.json does
+                if e.vessel.as_ref() == "Ship" && e.inventory.is_some() {
+                    state.cargo = e.into();
+                }
+            }
+
+            CargoTransfer(e) => {
+                if let Some(carrier) = state.docked_carrier() {
+                    for transfer in e.transfers.iter().filter(|t| t.r#type.as_ref() == "tritium") {
+                        match transfer.direction.as_ref() {
+                            "tocarrier" => carrier.tritium_reserve += transfer.count,
+                            "toship" => carrier.tritium_reserve = carrier.tritium_reserve.saturating_sub(transfer.count),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+            CargoDepot(e) => {
+                if let (Some(commodity), Some(count)) = (e.cargo_type.as_deref(), e.count) {
+                    match e.update_type.as_ref() {
+                        "Collect" => state.cargo.add(commodity, None, count, false, Some(e.mission_id)),
+                        "Deliver" => state.cargo.remove(commodity, count, false, Some(e.mission_id)),
+                        _ => {}
+                    }
+                }
+            }
+
+            CollectCargo(e) => {
+                state.mining.collected(e.timestamp);
+                state.cargo.add(&e.r#type, e.type_localised, 1, e.stolen, e.mission_id)
+            }
+
+            EjectCargo(e) => {
+                state.cargo.remove(&e.r#type, e.count, false, e.mission_id);
+                state.trade_ledger.discard(&e.r#type, e.count);
+            }
 
             // CARRIER
-            CarrierLocation(_) => {}
-            CarrierJump(_) => {}
-            CarrierBuy(_) => {}
-            CarrierStats(_) => {}
-            CarrierJumpRequest(_) => {}
-            CarrierDecommission(_) => {}
-            CarrierCancelDecommission(_) => {}
-            CarrierBankTransfer(_) => {}
-            CarrierDepositFuel(_) => {}
-            CarrierCrewServices(_) => {}
-            CarrierFinance(_) => {}
             CarrierShipPack(_) => {}
             CarrierModulePack(_) => {}
-            CarrierTradeOrder(_) => {}
-            CarrierDockingPermission(_) => {}
-            CarrierNameChange(_) => {}
-            CarrierJumpCancelled(_) => {}
-            FCMaterials(_) => {}
+
+            CarrierBuy(e) => {
+                let carrier = state.carriers.entry(e.carrier_id).or_default();
+                carrier.carrier_id = e.carrier_id;
+                carrier.callsign = e.callsign;
+                carrier.complete_jump(e.location, e.system_address, None);
+            }
+
+            CarrierStats(e) => state.carriers.entry(e.carrier_id).or_default().update_from_stats(e),
+
+            CarrierLocation(e) => {
+                state.carriers
+                    .entry(e.carrier_id)
+                    .or_default()
+                    .complete_jump(e.star_system, e.system_address, None);
+            }
+
+            CarrierJump(e) => {
+                // only emitted while docked, so the market is the carrier that jumped
+                if let Some(carrier) = e.market_id.and_then(|id| state.carriers.get_mut(&id)) {
+                    carrier.complete_jump(e.star_system, e.system_address, Some(e.body));
+                }
+            }
+
+            CarrierJumpRequest(e) => {
+                state.carriers.entry(e.carrier_id).or_default().pending_jump = Some(e.into());
+            }
+
+            CarrierJumpCancelled(e) => {
+                if let Some(carrier) = state.carriers.get_mut(&e.carrier_id) {
+                    carrier.pending_jump = None;
+                }
+            }
+
+            CarrierDecommission(e) => {
+                state.carriers.entry(e.carrier_id).or_default().pending_decommission =
+                    chrono::DateTime::from_timestamp(e.scrap_time as i64, 0);
+            }
+
+            CarrierCancelDecommission(e) => {
+                if let Some(carrier) = state.carriers.get_mut(&e.carrier_id) {
+                    carrier.pending_decommission = None;
+                }
+            }
+
+            CarrierBankTransfer(e) => {
+                let finance = &mut state.carriers.entry(e.carrier_id).or_default().finance;
+                let reserve = finance.reserve.min(e.carrier_balance);
+                finance.balance = e.carrier_balance;
+                finance.available = e.carrier_balance - reserve;
+            }
+
+            CarrierDepositFuel(e) => state.carriers.entry(e.carrier_id).or_default().fuel_level = e.total,
+
+            CarrierCrewServices(e) => state.carriers.entry(e.carrier_id).or_default().update_crew_service(e),
+
+            CarrierFinance(e) => state.carriers.entry(e.carrier_id).or_default().update_from_finance(e),
+
+            CarrierTradeOrder(e) => state.carriers.entry(e.carrier_id).or_default().update_trade_order(e),
+
+            CarrierDockingPermission(e) => {
+                state.carriers.entry(e.carrier_id).or_default().docking_access = Some(e.docking_access);
+            }
+
+            CarrierNameChange(e) => {
+                let carrier = state.carriers.entry(e.carrier_id).or_default();
+                carrier.name = e.name;
+                carrier.callsign = e.callsign;
+            }
+
+            FCMaterials(e) => {
+                if let Some(carrier) = state.carriers.get_mut(&e.market_id) {
+                    carrier.materials = e.into();
+                }
+            }
 
             // COLONISATION
             ColonisationBeaconDeployed(_) => {}
@@ -122,11 +214,23 @@ impl journal::Event {
             HoloscreenHacked(_) => {}
 
             // DATA MARKET
-            SellExplorationData(_) => {}
+            SellExplorationData(e) => {
+                state.exploration.sell(e.systems.iter().map(|s| s.as_ref()));
+            }
+
             BuyExplorationData(_) => {}
             BuyTradeData(_) => {}
-            SellOrganicData(_) => {}
-            MultiSellExplorationData(_) => {}
+            SellOrganicData(e) => {
+                let sold: Vec<_> = e.bio_data
+                    .into_iter()
+                    .map(|b| b.species_localised.unwrap_or(b.species))
+                    .collect();
+                state.exobiology.sell(&sold);
+            }
+
+            MultiSellExplorationData(e) => {
+                state.exploration.sell(e.discovered.iter().map(|d| d.system_name.as_ref()));
+            }
 
             RedeemVoucher(e) => {
                 let target = match e.r#type.as_ref() {
@@ -181,7 +285,7 @@ impl journal::Event {
             SelfDestruct(_) => {}
             SystemsShutdown(_) => {}
             ShieldState(_) => {}
-            LaunchDrone(_) => {}
+            LaunchDrone(e) => state.mining.launched(&e.r#type, &state.location.body_name, e.timestamp),
             DatalinkVoucher(_) => {}
             Scanned(_) => {}
 
@@ -202,7 +306,7 @@ impl journal::Event {
             Interdiction(_) => {}
             Interdicted(_) => {}
             EscapeInterdiction(_) => {}
-            SupercruiseEntry(_) => {}
+            SupercruiseEntry(_) => state.mining.end_session(),
             SupercruiseExit(_) => {}
             SupercruiseDestinationDrop(_) => {}
 
@@ -212,25 +316,44 @@ impl journal::Event {
 
             FSDJump(e) => {
                 // trim matching systems from the start of our nav route 
-                state.trim_nav_route(e.system_address);
+                let on_route = state.trim_nav_route(e.system_address);
+                state.fuel.jumped(e.jump_dist, e.fuel_used, e.fuel_level);
 
                 state.location.body_name = String::new().into();
                 state.location = e.into();
                 
                 if state.journal_loaded {
-                    return query::system(
-                        state.location.system_name.as_ref(),
-                        state.ship_loadout.max_jump_range);
+                    // more jumps to go along the route, so we might only be passing through
+                    if on_route && !state.nav_route.is_empty() {
+                        return query::system_debounced(state);
+                    }
+                    return query::system(state);
                 }
             }
 
             // FUEL
-            FuelScoop(_) => {}
-            ReservoirReplenished(_) => {}
+            FuelScoop(e) => state.fuel.scooped(e.scooped, e.total, e.timestamp),
+            ReservoirReplenished(e) => state.fuel.observe(e.fuel_main, e.fuel_reservoir),
 
             // MARKET
-            MarketBuy(_) => {}
-            MarketSell(_) => {}
+            MarketBuy(e) => {
+                state.trade_ledger.buy(&e.r#type, e.count, e.buy_price, e.timestamp);
+                state.cargo.add(&e.r#type, e.type_localised, e.count, false, None);
+            }
+
+            MarketSell(e) => {
+                state.trade_ledger.sell(trade::Sale {
+                    commodity: &e.r#type,
+                    count: e.count,
+                    price: e.sell_price,
+                    avg_price_paid: e.avg_price_paid,
+                    market_id: e.market_id,
+                    station_name: state.location.station_name.clone(),
+                    timestamp: e.timestamp,
+                });
+                state.cargo.remove(&e.r#type, e.count, e.stolen_goods.unwrap_or_default(), None);
+            }
+
             TechnologyBroker(_) => {}
 
             Market(e) => {
@@ -265,9 +388,13 @@ impl journal::Event {
             BuyMicroResources(_) => {}
 
             // MINING
-            ProspectedAsteroid(_) => {}
-            AsteroidCracked(_) => {}
-            MiningRefined(_) => {}
+            ProspectedAsteroid(e) => state.mining.prospected(e, &state.location.body_name),
+            AsteroidCracked(e) => state.mining.cracked(&e.body, e.timestamp),
+
+            MiningRefined(e) => {
+                let name = e.type_localised.unwrap_or_else(|| format::title_case(&e.r#type).into());
+                state.mining.refined(name, &state.location.body_name, e.timestamp);
+            }
 
             // MISSIONS
             Missions(_) => { /* this doesn't give us all the info we need */ }
@@ -284,7 +411,12 @@ impl journal::Event {
             // NAVIGATION
             ApproachBody(_) => {}
             LeaveBody(_) => {}
-            ApproachSettlement(_) => {}
+            ApproachSettlement(e) => {
+                if let (Some(latitude), Some(longitude)) = (e.latitude, e.longitude) {
+                    let name = e.name_localised.unwrap_or(e.name);
+                    state.surface.settlement(name, e.body_name, exobiology::Position { latitude, longitude });
+                }
+            }
             DockingRequested(_) => {}
             DockingGranted(_) => {}
             DockingTimeout(_) => {}
@@ -293,7 +425,8 @@ impl journal::Event {
             USSDrop(_) => {}
             Touchdown(_) => {}
             Liftoff(_) => {}
-            Undocked(_) => {}
+
+            Undocked(_) => state.location.docked = false,
             JetConeBoost(_) => {}
 
             NavRoute(e) => {
@@ -320,6 +453,10 @@ impl journal::Event {
             }
 
             Docked(e) => {
+                state.location.docked = true;
+                state.location.station_name = Some(e.station_name);
+                state.location.station_type = Some(e.station_type);
+
                 if let Some(active_fine) = e.active_fine {
                     state.crime.active_fine = active_fine;
                 }
@@ -390,6 +527,11 @@ impl journal::Event {
             }
 
             Status(e) => {
+                state.status = (&e).into();
+                if let Some(fuel) = &e.fuel {
+                    state.fuel.observe(fuel.fuel_main, fuel.fuel_reservoir);
+                }
+
                 if let Some(balance) = e.balance {
                     state.credits = (balance.separate_with_commas() + " CR").into();
                 }
@@ -397,6 +539,12 @@ impl journal::Event {
                     state.crime.legal_state = legal_state;
                 }
 
+                state.exobiology.planet_radius = e.planet_radius;
+                state.exobiology.position = match (e.latitude, e.longitude) {
+                    (Some(latitude), Some(longitude)) => Some(exobiology::Position { latitude, longitude }),
+                    _ => None,
+                };
+
                 if e.body_name.is_some() {
                     state.location.body_name = e.body_name.unwrap()
                 }
@@ -457,16 +605,48 @@ impl journal::Event {
                     progress.progress += 1;
                 }
 
+                let system_address = event.system_address;
+                let system_name = event.star_system.clone();
+                let sellable = event.scan_type.as_ref() != "NavBeaconDetail";
+
                 let body = system_scan.bodies
                     .entry(event.body_id as u8)
                     .or_default();
 
                 body.update_from_scan(event);
+
+                if sellable {
+                    state.exploration.record(system_address, &system_name, body);
+                }
             }
 
             ScanBaryCentre(_) => {}
-            ScanOrganic(_) => {}
-            CodexEntry(_) => {}
+            ScanOrganic(e) => {
+                let first_footfall = state.system_scans
+                    .get(&e.system_address)
+                    .and_then(|scan| scan.bodies.get(&(e.body as u8)))
+                    .is_some_and(|body| body.is_journal_scan && !body.was_footfalled);
+
+                if let (Some(body), Some(position)) = (state.status.body_name.clone(), state.exobiology.position) {
+                    let name = e.species_localised.clone().unwrap_or_else(|| e.species.clone());
+                    state.surface.sampled(name, body, position);
+                }
+
+                state.exobiology.scan(e, first_footfall);
+            }
+
+            CodexEntry(e) => {
+                if e.sub_category.as_ref() == "$Codex_SubCategory_Organic_Structures;"
+                    && let Some(body_id) = e.body_id
+                {
+                    state.exobiology.seen(
+                        e.system_address,
+                        body_id as u8,
+                        e.name_localised.unwrap_or(e.name),
+                    );
+                }
+            }
+
             DatalinkScan(_) => {}
             NavBeaconScan(_) => {}
             DiscoveryScan(_) => {}
@@ -537,7 +717,23 @@ impl journal::Event {
                     }
                 }).collect()
             }
-            SAAScanComplete(_) => {}
+            SAAScanComplete(e) => {
+                if let Some(body) = state.system_scans
+                    .get_mut(&e.system_address)
+                    .and_then(|scan| scan.bodies.get_mut(&(e.body_id as u8)))
+                {
+                    body.mapped = true;
+                    body.mapped_efficiently = e.probes_used <= e.efficiency_target;
+
+                    if body.is_journal_scan {
+                        let system_name = state.exploration.systems
+                            .get(&e.system_address)
+                            .map(|s| s.name.clone())
+                            .unwrap_or_default();
+                        state.exploration.record(e.system_address, &system_name, body);
+                    }
+                }
+            }
 
             // SESSION
             Continued(_) => {}
@@ -548,6 +744,11 @@ impl journal::Event {
             Fileheader(_) => {}
             SendText(_) => {}
             Died(_) => {
+                state.cargo = Default::default();
+                state.trade_ledger.clear_holdings();
+                state.exobiology.lose_samples();
+                state.exploration.clear();
+                state.mining.end_session();
                 state.missions.clear();
                 state.bounties.clear(); // todo: even bounties with no factions? gotta investigate this.
                 state.combat_bonds.clear();
@@ -555,8 +756,10 @@ impl journal::Event {
             Resurrect(_) => {}
             Music(_) => {}
 
-            LoadGame(_) => {
+            LoadGame(e) => {
                 state.nav_route.clear();
+                state.mining.end_session();
+                state.trade_ledger.start_session(e.timestamp);
             }
 
             ReceiveText(e) => {
@@ -590,8 +793,8 @@ impl journal::Event {
             }
 
             // SHIP MAINTENANCE
-            RefuelAll(_) => {}
-            RefuelPartial(_) => {}
+            RefuelAll(e) => state.fuel.refuelled(e.amount, state.ship_loadout.fuel_capacity.main),
+            RefuelPartial(e) => state.fuel.refuelled(e.amount, state.ship_loadout.fuel_capacity.main),
             RepairAll(_) => {}
             Repair(_) => {}
             Resupply(_) => {}
@@ -673,6 +876,9 @@ impl journal::Event {
             WingLeave(_) => {}
             ShipLockerBackpack(_) => {}
 
+            // no schema for this one yet
+            Unknown { event, .. } => state.diagnostics.unknown_event(&event),
+
         }
 
         Task::none()
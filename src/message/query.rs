@@ -1,38 +1,63 @@
 use crate::message::Message;
 use crate::state::*;
 use iced::Task;
-use crate::{ardent, edsm};
+use log::debug;
+use crate::{ardent, edsm, edsm_upload, query};
 use crate::message::Query::*;
+use crate::state::queries::Source;
+use chrono::Utc;
 
+/// System queries are tagged with the address of the system they were made for.
 #[derive(Clone, Debug)]
 pub enum Query {
-    StationsQueried(edsm::stations::Stations),
-    NearbySystemsQueried(Vec<ardent::NearbySystem>),
-    BodiesQueried(edsm::bodies::Bodies),
-    TrafficQueried(edsm::traffic::Traffic),
-    DeathsQueried(edsm::deaths::Deaths),
+    StationsQueried(u64, edsm::stations::Stations),
+    NearbySystemsQueried(u64, Vec<ardent::NearbySystem>),
+    BodiesQueried(u64, edsm::bodies::Bodies),
+    TrafficQueried(u64, edsm::traffic::Traffic),
+    DeathsQueried(u64, edsm::deaths::Deaths),
+    Failed(u64, Source, Box<str>),
+
+    // Sent after a debounced jump, to query the system if we're still there
+    Settled(u64),
 
     // EDSM status updates
     EdsmServerStatus(edsm::ServerStatus),
+    EdsmUploadStatus(edsm_upload::Status),
 }
 
 impl Query {
     pub fn update(self, state: &mut State) -> Task<Message> {
+        if let Some(address) = self.system_address() && address != state.location.system_address {
+            // we've jumped again since this was asked for
+            debug!("Dropping query response for system {}", address);
+            return Task::none();
+        }
+
+        if let Some(source) = self.source() {
+            state.queries.succeeded(source, Utc::now());
+        }
+
         match self {
             
-            StationsQueried(response) => state.location.stations = response.into(),
+            StationsQueried(_, response) => state.location.stations = response.into(),
             
-            TrafficQueried(traffic) => state.location.traffic = Some(traffic.into()),
+            TrafficQueried(_, traffic) => state.location.traffic = Some(traffic.into()),
             
-            DeathsQueried(deaths) => state.location.deaths = Some(deaths.into()),
+            DeathsQueried(_, deaths) => state.location.deaths = Some(deaths.into()),
             
             EdsmServerStatus(status) => state.edsm_server_status = Some(status.into()),
+
+            EdsmUploadStatus(status) => state.edsm_upload = Some(status),
+
+            Failed(_, source, reason) => state.queries.failed(source, reason, Utc::now()),
+
+            Settled(_) => return query::system(state),
             
-            NearbySystemsQueried(systems) => {
+            NearbySystemsQueried(_, systems) => {
                 state.location.nearby_systems = systems.into_iter().map(|s| s.into()).collect();
             }
 
-            BodiesQueried(response) => {
+            BodiesQueried(_, response) => {
                 if let Some(queried_bodies) = response.bodies && let Some (system_id) = response.id64 {
                     for queried_body in queried_bodies.into_iter() {
                         let system_scan = state.system_scans.entry(system_id).or_default();
@@ -46,4 +71,29 @@ impl Query {
 
         Task::none()
     }
+
+    /// The system query this answers, if any.
+    fn source(&self) -> Option<Source> {
+        match self {
+            StationsQueried(..) => Some(Source::Stations),
+            NearbySystemsQueried(..) => Some(Source::NearbySystems),
+            BodiesQueried(..) => Some(Source::Bodies),
+            TrafficQueried(..) => Some(Source::Traffic),
+            DeathsQueried(..) => Some(Source::Deaths),
+            Failed(..) | Settled(_) | EdsmServerStatus(_) | EdsmUploadStatus(_) => None,
+        }
+    }
+
+    fn system_address(&self) -> Option<u64> {
+        match self {
+            StationsQueried(address, _)
+            | NearbySystemsQueried(address, _)
+            | BodiesQueried(address, _)
+            | TrafficQueried(address, _)
+            | DeathsQueried(address, _)
+            | Failed(address, ..)
+            | Settled(address) => Some(*address),
+            EdsmServerStatus(_) | EdsmUploadStatus(_) => None,
+        }
+    }
 }
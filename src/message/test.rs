@@ -0,0 +1,161 @@
+//! Feeds fixture journals from `src/test_data/journals` through `Event::update` and checks the
+//! resulting `State`, both with targeted assertions and against golden snapshots in
+//! `src/test_data/golden` of the sections each fixture exercises.
+//!
+//! Golden files are only written when the tests are run with `UPDATE_GOLDEN=1`; a missing one
+//! fails the test like any other difference. Review the diff before committing them.
+
+use crate::journal::Event;
+use crate::state::State;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+
+const JOURNALS: &str = "src/test_data/journals";
+const GOLDEN: &str = "src/test_data/golden";
+
+/// Applies every line of a fixture journal to a fresh state, in order. Tasks returned by the
+/// updates (API queries and the like) are dropped, so the result only depends on the fixture.
+fn replay(fixture: &str) -> State {
+    let path = PathBuf::from(JOURNALS).join(format!("{}.log", fixture));
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+
+    let mut state = State::default();
+    for (number, line) in content.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+        let event: Event = serde_json::from_str(line)
+            .unwrap_or_else(|e| panic!("{} line {}: {}", path.display(), number + 1, e));
+        let _ = event.update(&mut state);
+    }
+    state
+}
+
+/// The given sections of the persisted state, so a golden only changes with what its fixture
+/// exercises. Objects serialize with sorted keys, so the snapshot doesn't depend on hash map
+/// ordering.
+fn snapshot(state: &State, sections: &[&str]) -> Value {
+    let state = serde_json::to_value(state).expect("State should always serialize");
+    sections
+        .iter()
+        .map(|&section| {
+            let value = state.get(section).unwrap_or_else(|| panic!("State has no section {}", section));
+            (section, value.clone())
+        })
+        .collect()
+}
+
+fn assert_golden(name: &str, state: &State, sections: &[&str]) {
+    let path = PathBuf::from(GOLDEN).join(format!("{}.json", name));
+    let actual = snapshot(state, sections);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(GOLDEN).unwrap();
+        fs::write(&path, serde_json::to_string_pretty(&actual).unwrap() + "\n").unwrap();
+        return;
+    }
+
+    let content = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!("Failed to read {}: {}; run with UPDATE_GOLDEN=1 to create it", path.display(), e)
+    });
+    let expected: Value = serde_json::from_str(&content)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+
+    assert!(
+        actual == expected,
+        "State differs from {}; rerun with UPDATE_GOLDEN=1 if the change is intended.\nactual:\n{}",
+        path.display(),
+        serde_json::to_string_pretty(&actual).unwrap()
+    );
+}
+
+#[test]
+fn fsd_jump_moves_the_commander() {
+    let state = replay("fsd_jump");
+
+    assert_eq!(state.location.system_name.as_ref(), "Sol");
+    assert_eq!(state.location.system_address, 10477373803);
+    assert_golden("fsd_jump", &state, &["location", "fuel"]);
+}
+
+#[test]
+fn scan_records_bodies_and_unsold_data() {
+    let state = replay("scan");
+
+    let body = &state.system_scans[&4356789].bodies[&12];
+    assert_eq!(body.name.as_ref(), "Procyon B 3 a");
+    assert!(body.is_journal_scan);
+    assert_eq!(state.exploration.unsold_value(), 500);
+    assert_golden("scan", &state, &["system_scans", "exploration"]);
+}
+
+#[test]
+fn completed_missions_are_removed() {
+    let state = replay("missions");
+
+    let ids: Vec<u64> = state.missions.iter().map(|m| m.mission_id).collect();
+    assert_eq!(ids, vec![65393627]);
+    assert_golden("missions", &state, &["missions"]);
+}
+
+#[test]
+fn powerplay_tracks_pledge_and_salary() {
+    let state = replay("powerplay");
+
+    assert_eq!(state.powerplay.power.as_deref(), Some("Li Yong-Rui"));
+    assert_eq!(state.powerplay.rank, Some(9));
+    assert_eq!(state.powerplay.merits, 51628);
+    assert_eq!(state.powerplay.last_salary, Some(50000));
+    assert_golden("powerplay", &state, &["powerplay"]);
+}
+
+#[test]
+fn death_loses_missions_claims_and_unsold_data() {
+    let state = replay("death");
+
+    assert!(state.missions.is_empty());
+    assert!(state.bounties.is_empty());
+    assert_eq!(state.exploration.unsold_value(), 0);
+    assert!(state.system_scans.contains_key(&4356789));
+    assert_golden("death", &state, &["missions", "bounties", "exploration", "system_scans"]);
+}
+
+#[test]
+fn unknown_events_are_kept_and_counted() {
+    let line = r#"{ "timestamp":"3311-05-01T12:00:00Z", "event":"SomethingFrontierAddedLater", "Detail":42 }"#;
+    let event: Event = serde_json::from_str(line).unwrap();
+
+    let Event::Unknown { event: name, .. } = &event else { panic!("Expected the Unknown fallback, got {:?}", event) };
+    assert_eq!(name.as_ref(), "SomethingFrontierAddedLater");
+    assert_eq!(event.timestamp().to_rfc3339(), "3311-05-01T12:00:00+00:00");
+    assert_eq!(serde_json::to_value(&event).unwrap(), serde_json::from_str::<Value>(line).unwrap());
+
+    let mut state = State::default();
+    let _ = event.update(&mut state);
+    assert_eq!(state.diagnostics.unknown_events["SomethingFrontierAddedLater"], 1);
+}
+
+#[test]
+fn known_events_that_dont_match_their_schema_still_fail() {
+    let line = r#"{ "timestamp":"3311-05-01T12:00:00Z", "event":"FSDJump", "StarSystem":42 }"#;
+    assert!(serde_json::from_str::<Event>(line).is_err());
+}
+
+#[test]
+fn codex_entries_and_samples_share_a_species() {
+    let lines = [
+        r#"{ "timestamp":"3311-05-01T12:00:00Z", "event":"CodexEntry", "EntryID":2420708, "Name":"$Codex_Ent_Stratum_07_F_Name;", "Name_Localised":"Stratum Tectonicas - Green", "SubCategory":"$Codex_SubCategory_Organic_Structures;", "SubCategory_Localised":"Organic structures", "Category":"$Codex_Category_Biology;", "Category_Localised":"Biological and Geological", "Region":"$Codex_RegionName_18;", "Region_Localised":"Inner Orion Spur", "System":"Procyon", "SystemAddress":4356789, "BodyID":12, "Latitude":12.5, "Longitude":-45.25, "IsNewEntry":true }"#,
+        r#"{ "timestamp":"3311-05-01T12:05:00Z", "event":"ScanOrganic", "ScanType":"Log", "Genus":"$Codex_Ent_Stratum_Genus_Name;", "Genus_Localised":"Stratum", "Species":"$Codex_Ent_Stratum_07_Name;", "Species_Localised":"Stratum Tectonicas", "Variant":"$Codex_Ent_Stratum_07_F_Name;", "Variant_Localised":"Stratum Tectonicas - Green", "SystemAddress":4356789, "Body":12 }"#,
+    ];
+
+    let mut state = State::default();
+    for line in lines {
+        let event: Event = serde_json::from_str(line).unwrap();
+        let _ = event.update(&mut state);
+    }
+
+    let species = state.exobiology.species(4356789, 12);
+    assert_eq!(species.len(), 1);
+    assert_eq!(species[0].name.as_ref(), "Stratum Tectonicas");
+    assert_eq!(species[0].variant.as_deref(), Some("Stratum Tectonicas - Green"));
+    assert_eq!(species[0].stage, crate::state::exobiology::Stage::Logged);
+}
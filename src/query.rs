@@ -3,52 +3,74 @@ use log::{info, warn};
 use crate::ardent::ArdentClient;
 use crate::edsm::EdsmClient;
 use std::sync::LazyLock;
+use std::time::Duration;
 use crate::message::{Message, Query};
+use crate::state::queries::Source;
+use crate::state::State;
 
-pub fn system(star_system: &str, radius: f32) -> Task<Message> {
-    static EDSM: LazyLock<EdsmClient> = LazyLock::new(|| EdsmClient::default());
-    static ARDENT: LazyLock<ArdentClient> = LazyLock::new(|| ArdentClient::default());
+static EDSM: LazyLock<EdsmClient> = LazyLock::new(|| EdsmClient::default());
+static ARDENT: LazyLock<ArdentClient> = LazyLock::new(|| ArdentClient::default());
+
+/// How long to stay in a system along a route before it's worth querying. Long enough to cover
+/// charging the next jump, so systems we only pass through aren't queried at all.
+const DEBOUNCE: Duration = Duration::from_secs(20);
+
+/// Queries everything we show about the current system.
+pub fn system(state: &mut State) -> Task<Message> {
+    sources(state, Source::ALL)
+}
+
+/// Queries the current system once we've been there a while, for jumps partway along a route.
+/// The sources show as loading in the meantime.
+pub fn system_debounced(state: &mut State) -> Task<Message> {
+    let address = state.location.system_address;
+    state.queries.started(address, &state.location.system_name, Source::ALL, None);
+
+    Task::perform(tokio::time::sleep(DEBOUNCE), move |_| Message::Query(Query::Settled(address)))
+}
+
+/// Queries the given sources for the current system, marking them as in flight. Each one answers
+/// with its `Query` message, or `Query::Failed` if it couldn't be fetched. Anything still running
+/// for the previous system is cancelled.
+pub fn sources(state: &mut State, sources: &[Source]) -> Task<Message> {
+    let address = state.location.system_address;
+    let star_system = state.location.system_name.to_string();
+    let radius = state.ship_loadout.max_jump_range;
 
     info!("Querying system: {}", star_system);
+    let (task, handle) = Task::batch(sources.iter()
+        .map(|source| fetch(*source, address, star_system.clone(), radius)))
+        .abortable();
+    state.queries.started(address, &star_system, sources, Some(handle));
+    task
+}
 
-    macro_rules! fetch_edsm {
-            ($method:ident, $Msg:ident, $label:literal) => {{
-                let name = star_system.to_string();
-                Task::perform(async move {
-                    match EDSM.$method(name.as_ref()).await {
-                        Ok(v) => Message::Query(Query::$Msg(v)),
-                        Err(error) => { warn!("Failed to fetch {}: {}", $label, error); Message::Empty }
-                    }
-                }, |m| m)
-            }};
-            ($method:ident, $arg:expr, $Msg:ident, $label:literal) => {{
-                let name = star_system.to_string();
-                Task::perform(async move {
-                    match EDSM.$method(name.as_ref(), $arg).await {
-                        Ok(v) => Message::Query(Query::$Msg(v)),
-                        Err(error) => { warn!("Failed to fetch {}: {}", $label, error); Message::Empty }
-                    }
-                }, |m| m)
-            }};
-        }
-    
-    Task::batch(vec![
-        fetch_edsm!(get_bodies, BodiesQueried, "bodies"),
-        fetch_edsm!(get_stations, StationsQueried, "stations"),
-        fetch_edsm!(get_traffic, TrafficQueried, "traffic"),
-        fetch_edsm!(get_deaths, DeathsQueried, "deaths"),
-        {
-            let star_system = star_system.to_string();
-            Task::perform(async move {
-                let nearby_systems = ARDENT.get_nearby_systems(&star_system, Some(radius))
-                    .await
-                    .map(|systems| Message::Query(Query::NearbySystemsQueried(systems)))
-                    .unwrap_or_else(|error| {
-                        warn!("Failed to fetch nearby systems: {}", error);
-                        Message::Empty
-                    });
-                nearby_systems
-            }, |m| m)
+fn fetch(source: Source, address: u64, star_system: String, radius: f32) -> Task<Message> {
+    Task::perform(async move {
+        let result = match source {
+            Source::Bodies => EDSM.get_bodies(&star_system).await
+                .map(|r| Query::BodiesQueried(address, r))
+                .map_err(|e| e.to_string()),
+            Source::Stations => EDSM.get_stations(&star_system).await
+                .map(|r| Query::StationsQueried(address, r))
+                .map_err(|e| e.to_string()),
+            Source::Traffic => EDSM.get_traffic(&star_system).await
+                .map(|r| Query::TrafficQueried(address, r))
+                .map_err(|e| e.to_string()),
+            Source::Deaths => EDSM.get_deaths(&star_system).await
+                .map(|r| Query::DeathsQueried(address, r))
+                .map_err(|e| e.to_string()),
+            Source::NearbySystems => ARDENT.get_nearby_systems(&star_system, Some(radius)).await
+                .map(|r| Query::NearbySystemsQueried(address, r))
+                .map_err(|e| e.to_string()),
+        };
+
+        match result {
+            Ok(query) => Message::Query(query),
+            Err(error) => {
+                warn!("Failed to fetch {}: {}", source.label(), error);
+                Message::Query(Query::Failed(address, source, error.into()))
+            }
         }
-    ])
-}
\ No newline at end of file
+    }, |m| m)
+}
@@ -0,0 +1,288 @@
+//! Shared request policy for the HTTP clients (EDSM, Ardent and the EDDN uploader).
+//!
+//! Every request goes through `RequestPolicy`, which caps how many requests run against one host
+//! at a time, gives each attempt a timeout, and retries transient failures (timeouts, connection
+//! errors, 5xx and 429 responses) with exponential backoff and jitter. A 429 puts the whole host
+//! on hold for its `Retry-After`, so concurrent requests don't keep hammering it either.
+
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use reqwest::header::{HeaderMap, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{RequestBuilder, StatusCode, Url};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::Instant;
+
+static HOSTS: LazyLock<Mutex<HashMap<Box<str>, Arc<Host>>>> = LazyLock::new(Default::default);
+
+#[derive(Debug, Clone)]
+pub struct RequestPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled for each retry after that
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Longest `Retry-After` worth waiting for; anything longer fails straight away
+    pub max_retry_after: Duration,
+    /// Time allowed for each attempt, including reading the body
+    pub timeout: Duration,
+    /// Requests allowed in flight at once per host, counted separately for each limit in use
+    pub max_per_host: usize,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_retry_after: Duration::from_secs(120),
+            timeout: Duration::from_secs(20),
+            max_per_host: 4,
+        }
+    }
+}
+
+/// Why a request failed, after any retries.
+#[derive(thiserror::Error, Debug)]
+pub enum RequestError {
+    #[error("request to {url} timed out")]
+    Timeout { url: Box<str> },
+    #[error("{status} from {url}")]
+    Status { status: StatusCode, url: Box<str> },
+    #[error("rate limited by {url}{}", retry_after.map(|d| format!(", retry after {}s", d.as_secs())).unwrap_or_default())]
+    RateLimited { url: Box<str>, retry_after: Option<Duration> },
+    #[error("http error: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+impl RequestError {
+    /// Whether trying again later might succeed.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            RequestError::Timeout { .. } | RequestError::RateLimited { .. } => true,
+            RequestError::Status { status, .. } => status.is_server_error(),
+            RequestError::Http(e) => e.is_connect() || e.is_timeout(),
+        }
+    }
+
+    fn from_reqwest(error: reqwest::Error, url: &Url) -> Self {
+        if error.is_timeout() {
+            RequestError::Timeout { url: url.as_str().into() }
+        } else {
+            RequestError::Http(error)
+        }
+    }
+}
+
+/// Rate limiting is shared by everything talking to a host, but each concurrency limit gets its
+/// own permits, so a policy allowing one request at a time isn't handed another policy's limit.
+struct Host {
+    permits: Mutex<HashMap<usize, Arc<Semaphore>>>,
+    blocked_until: Mutex<Option<Instant>>,
+}
+
+impl Host {
+    fn get(url: &Url) -> Arc<Host> {
+        let key: Box<str> = url.host_str().unwrap_or_default().into();
+        HOSTS.lock()
+            .unwrap()
+            .entry(key)
+            .or_insert_with(|| Arc::new(Host {
+                permits: Mutex::new(HashMap::new()),
+                blocked_until: Mutex::new(None),
+            }))
+            .clone()
+    }
+
+    fn permits(&self, limit: usize) -> Arc<Semaphore> {
+        let limit = limit.max(1);
+        self.permits.lock()
+            .unwrap()
+            .entry(limit)
+            .or_insert_with(|| Arc::new(Semaphore::new(limit)))
+            .clone()
+    }
+
+    fn block_for(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut blocked = self.blocked_until.lock().unwrap();
+        if blocked.is_none_or(|current| current < until) {
+            *blocked = Some(until);
+        }
+    }
+
+    async fn wait_until_unblocked(&self) {
+        let until = *self.blocked_until.lock().unwrap();
+        if let Some(until) = until {
+            tokio::time::sleep_until(until).await;
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// GETs `url` and returns the response body, retrying transient failures.
+    pub async fn get(&self, http: &reqwest::Client, url: &Url) -> Result<String, RequestError> {
+        self.send(http, url, || http.get(url.clone())).await
+    }
+
+    /// POSTs `body` as JSON to `url` and returns the response body, retrying transient failures.
+    pub async fn post_json(&self, http: &reqwest::Client, url: &Url, body: &Value) -> Result<String, RequestError> {
+        self.send(http, url, || http.post(url.clone()).json(body)).await
+    }
+
+    /// POSTs `fields` as a URL-encoded form to `url` and returns the response body, retrying
+    /// transient failures.
+    pub async fn post_form(&self, http: &reqwest::Client, url: &Url, fields: &[(&str, &str)]) -> Result<String, RequestError> {
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(fields)
+            .finish();
+        self.send(http, url, || {
+            http.post(url.clone())
+                .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(body.clone())
+        }).await
+    }
+
+    async fn send(
+        &self,
+        http: &reqwest::Client,
+        url: &Url,
+        request: impl Fn() -> RequestBuilder,
+    ) -> Result<String, RequestError> {
+        let host = Host::get(url);
+        let permits = host.permits(self.max_per_host);
+        let mut retries = 0;
+
+        loop {
+            host.wait_until_unblocked().await;
+            let result = {
+                let _permit = permits.acquire().await.expect("Host semaphore is never closed");
+                self.attempt(http, url, request()).await
+            };
+
+            let error = match result {
+                Ok(body) => return Ok(body),
+                Err(e) if retries >= self.max_retries || !e.is_transient() => return Err(e),
+                Err(e) => e,
+            };
+
+            let delay = match &error {
+                RequestError::RateLimited { retry_after: Some(wait), .. } if *wait > self.max_retry_after => {
+                    return Err(error);
+                }
+                RequestError::RateLimited { retry_after, .. } => {
+                    let wait = retry_after.unwrap_or_else(|| self.backoff(retries, rand::random()));
+                    host.block_for(wait);
+                    wait
+                }
+                _ => self.backoff(retries, rand::random()),
+            };
+
+            retries += 1;
+            warn!("{}; retry {}/{} in {:.1}s", error, retries, self.max_retries, delay.as_secs_f64());
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn attempt(&self, http: &reqwest::Client, url: &Url, request: RequestBuilder) -> Result<String, RequestError> {
+        let request = request.timeout(self.timeout).build()?;
+        let method = request.method().clone();
+        info!("{} {}", method, url);
+        let response = http.execute(request)
+            .await
+            .map_err(|e| RequestError::from_reqwest(e, url))?;
+
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(RequestError::RateLimited {
+                url: url.as_str().into(),
+                retry_after: retry_after(response.headers(), Utc::now()),
+            });
+        }
+
+        let text = response.text().await.map_err(|e| RequestError::from_reqwest(e, url))?;
+        if !status.is_success() {
+            info!("{} {} for {}", method, status, url);
+            return Err(RequestError::Status { status, url: url.as_str().into() });
+        }
+        Ok(text)
+    }
+
+    /// Delay before retry number `retries + 1`: the base delay doubled per retry, capped, then
+    /// scaled into its upper half by `jitter` (0..1) so clients that failed together don't all
+    /// come back at the same moment.
+    fn backoff(&self, retries: u32, jitter: f64) -> Duration {
+        let exponential = self.base_delay.saturating_mul(2u32.saturating_pow(retries)).min(self.max_delay);
+        exponential.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// Parses a `Retry-After` header, which is either a number of seconds or an HTTP date.
+fn retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.with_timezone(&Utc) - now).to_std().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use reqwest::header::HeaderValue;
+
+    fn headers(retry_after: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(retry_after).unwrap());
+        headers
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RequestPolicy::default();
+
+        assert_eq!(policy.backoff(0, 1.0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1, 1.0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3, 1.0), Duration::from_secs(4));
+        assert_eq!(policy.backoff(20, 1.0), policy.max_delay);
+        assert_eq!(policy.backoff(1, 0.0), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_dates() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+
+        assert_eq!(retry_after(&headers("120"), now), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after(&headers("Wed, 21 Oct 2015 07:28:30 GMT"), now), Some(Duration::from_secs(30)));
+        assert_eq!(retry_after(&headers("Wed, 21 Oct 2015 07:00:00 GMT"), now), Some(Duration::ZERO));
+        assert_eq!(retry_after(&headers("soon"), now), None);
+        assert_eq!(retry_after(&HeaderMap::new(), now), None);
+    }
+
+    #[test]
+    fn only_transient_failures_are_retried() {
+        let url: Box<str> = "https://www.edsm.net/".into();
+
+        assert!(RequestError::Timeout { url: url.clone() }.is_transient());
+        assert!(RequestError::RateLimited { url: url.clone(), retry_after: None }.is_transient());
+        assert!(RequestError::Status { status: StatusCode::BAD_GATEWAY, url: url.clone() }.is_transient());
+        assert!(!RequestError::Status { status: StatusCode::NOT_FOUND, url }.is_transient());
+    }
+
+    #[test]
+    fn policies_for_the_same_host_keep_their_own_limits() {
+        let url = Url::parse("https://limits.example/api").unwrap();
+        let host = Host::get(&url);
+
+        assert!(Arc::ptr_eq(&host, &Host::get(&url)));
+        assert_eq!(host.permits(1).available_permits(), 1);
+        assert_eq!(host.permits(4).available_permits(), 4);
+        assert!(Arc::ptr_eq(&host.permits(1), &host.permits(0)));
+    }
+}
@@ -1,46 +1,73 @@
+pub mod cargo;
+pub mod carrier;
 pub mod chat;
+pub mod diagnostics;
 pub mod engineering;
+pub mod exobiology;
+pub mod exploration;
 pub mod fss;
+pub mod fuel;
 pub mod history;
 pub mod layout;
 pub mod market;
 pub mod material;
+pub mod mining;
 pub mod mission;
 pub mod navigation;
 pub mod personal;
 pub mod powerplay;
+pub mod queries;
 pub mod server;
 pub mod ship;
+pub mod status;
 pub mod suit;
+pub mod surface;
+pub mod trade;
 
+use crate::state::cargo::Cargo;
+use crate::state::carrier::Carrier;
 use crate::state::chat::Message;
+use crate::state::diagnostics::Diagnostics;
 use crate::state::engineering::Engineer;
+use crate::state::exobiology::Exobiology;
+use crate::state::exploration::Exploration;
 use crate::state::fss::Fss;
 use crate::state::history::EventLog;
 use crate::state::layout::Layout;
 use crate::state::market::Market;
 use crate::state::material::Materials;
+use crate::state::mining::Mining;
 use crate::state::mission::Mission;
 use crate::state::navigation::{CurrentLocation, NavRouteStep};
 use crate::state::personal::{CrimeStats, Rank, Reputation};
 use crate::state::powerplay::Powerplay;
+use crate::state::queries::Queries;
 use crate::state::server::Status;
-use serde::Deserialize;
+use crate::journal::{checkpoint, replay, LoadProgress};
+use crate::edsm_upload;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Everything we know about the commander, built up from journal events and API queries.
+/// Fields that only make sense for the running app are left out of the persisted checkpoint.
+#[derive(Serialize, Deserialize)]
 pub struct State {
     pub commander_name: Box<str>,
     pub credits: Box<str>,
     pub location: CurrentLocation,
     pub ship_locker: ship::Locker,
     pub ship_loadout: ship::Loadout,
+    pub cargo: Cargo,
     pub suit_loadout: suit::Loadout,
+    #[serde(skip)]
     pub active_screen: Screen,
     pub materials: Materials,
     pub messages: Vec<Message>,
     pub logs: Vec<EventLog>,
     pub crime: CrimeStats,
     pub market: Market,
+    pub trade_ledger: trade::Ledger,
+    pub mining: Mining,
     pub rank: Rank,
     pub reputation: Reputation,
     pub engineers: Vec<Engineer>,
@@ -49,14 +76,36 @@ pub struct State {
     pub combat_bonds: HashMap<Box<str>, u32>,
     pub bounties: HashMap<Box<str>, u32>,
     pub discoveries: HashMap<Box<str>, u32>,
+    pub exobiology: Exobiology,
+    pub exploration: Exploration,
     pub progress: Rank,
     pub powerplay: Powerplay,
+    pub carriers: HashMap<u64, Carrier>,
+    #[serde(skip)]
     pub edsm_server_status: Option<Status>,
+    #[serde(skip)]
+    pub queries: Queries,
+    #[serde(skip)]
+    pub edsm_upload: Option<edsm_upload::Status>,
+    #[serde(skip)]
+    pub diagnostics: Diagnostics,
+    #[serde(skip)]
+    pub status: status::Status,
+    pub fuel: fuel::Fuel,
+    pub surface: surface::Surface,
+    #[serde(skip)]
     pub journal_loaded: bool,
+    #[serde(skip)]
+    pub journal_position: Option<checkpoint::Position>,
+    #[serde(skip)]
+    pub load_progress: Option<LoadProgress>,
+    #[serde(skip)]
+    pub replay: Option<replay::Status>,
     pub first_message_timestamp: i64,
     pub latest_message_timestamp: i64,
     pub latest_message_timestamp_formatted: Box<str>,
 
+    #[serde(skip, default = "Layout::from_settings")]
     pub layout: Layout,
     pub system_scans: HashMap<u64, Fss>,
 }
@@ -77,6 +126,7 @@ impl Default for State {
             location: Default::default(),
             ship_locker: Default::default(),
             ship_loadout: Default::default(),
+            cargo: Default::default(),
             suit_loadout: Default::default(),
             active_screen: Default::default(),
             materials: Default::default(),
@@ -84,6 +134,8 @@ impl Default for State {
             logs: Vec::new(),
             crime: Default::default(),
             market: Default::default(),
+            trade_ledger: Default::default(),
+            mining: Default::default(),
             rank: Default::default(),
             reputation: Default::default(),
             engineers: Default::default(),
@@ -92,10 +144,22 @@ impl Default for State {
             combat_bonds: HashMap::new(),
             bounties: HashMap::new(),
             discoveries: HashMap::new(),
+            exobiology: Default::default(),
+            exploration: Default::default(),
             progress: Default::default(),
             powerplay: Default::default(),
+            carriers: HashMap::new(),
             edsm_server_status: None,
+            queries: Default::default(),
+            edsm_upload: None,
+            diagnostics: Default::default(),
+            status: Default::default(),
+            fuel: Default::default(),
+            surface: Default::default(),
             journal_loaded: false,
+            journal_position: None,
+            load_progress: None,
+            replay: None,
             first_message_timestamp: 0,
             latest_message_timestamp: 0,
             latest_message_timestamp_formatted: String::new().into(),
@@ -108,15 +172,37 @@ impl Default for State {
 }
 
 impl State {
-    pub fn trim_nav_route(&mut self, address_inclusive_to_trim: u64) {
-        if !self.nav_route.is_empty() {
-            if let Some(pos) = self
-                .nav_route
-                .iter()
-                .position(|step| step.system_address == address_inclusive_to_trim)
-            {
-                self.nav_route.drain(0..=pos);
-            }
+
+    /// Starts from the saved checkpoint when there is a usable one, otherwise from scratch.
+    pub fn restore() -> Self {
+        crate::journal::get_directory()
+            .ok()
+            .and_then(|dir| checkpoint::load(&dir))
+            .unwrap_or_default()
+    }
+
+    /// Drops the route up to and including the given system. Returns whether it was on the route.
+    pub fn trim_nav_route(&mut self, address_inclusive_to_trim: u64) -> bool {
+        let Some(pos) = self
+            .nav_route
+            .iter()
+            .position(|step| step.system_address == address_inclusive_to_trim)
+        else {
+            return false;
+        };
+        self.nav_route.drain(0..=pos);
+        true
+    }
+
+    /// The carrier we are currently docked at, if it is one of ours.
+    pub fn docked_carrier(&mut self) -> Option<&mut Carrier> {
+        if !self.location.docked {
+            return None;
         }
+
+        let station_name = self.location.station_name.as_deref()?;
+        self.carriers
+            .values_mut()
+            .find(|c| c.callsign.as_ref() == station_name)
     }
 }
@@ -0,0 +1,133 @@
+use crate::journal::event;
+use crate::journal::format::title_case;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Cargo {
+    pub items: Vec<CargoItem>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CargoItem {
+    pub commodity: Box<str>,
+    pub name: Box<str>,
+    pub count: u64,
+    pub stolen: u64,
+    pub mission_id: Option<u64>,
+}
+
+impl Cargo {
+
+    /// Total tonnage currently in the hold.
+    pub fn used(&self) -> u64 {
+        self.items.iter().map(|i| i.count).sum()
+    }
+
+    pub fn add(&mut self, commodity: &str, name: Option<Box<str>>, count: u64, stolen: bool, mission_id: Option<u64>) {
+        let commodity = commodity.to_lowercase();
+        let stolen = if stolen { count } else { 0 };
+
+        match self.items.iter_mut().find(|i| i.commodity.as_ref() == commodity && i.mission_id == mission_id) {
+            Some(item) => {
+                item.count += count;
+                item.stolen += stolen;
+            }
+            None => {
+                self.items.push(CargoItem {
+                    name: name.unwrap_or_else(|| title_case(&commodity).into()),
+                    commodity: commodity.into(),
+                    count,
+                    stolen,
+                    mission_id,
+                });
+                self.items.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+    }
+
+    /// Removes cargo from the entries for `commodity` and `mission_id`, so mission cargo is only
+    /// touched when its mission is given. Selling stolen goods (`stolen`) takes from the stolen
+    /// count first; anything else takes clean goods first, leaving stolen ones until last.
+    pub fn remove(&mut self, commodity: &str, count: u64, stolen: bool, mission_id: Option<u64>) {
+        let commodity = commodity.to_lowercase();
+        let mut remaining = count;
+
+        for item in self.items.iter_mut()
+            .filter(|i| i.commodity.as_ref() == commodity && i.mission_id == mission_id)
+        {
+            let taken = remaining.min(item.count);
+            item.count -= taken;
+            item.stolen = if stolen {
+                item.stolen.saturating_sub(taken)
+            } else {
+                item.stolen.min(item.count)
+            };
+            remaining -= taken;
+
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        self.items.retain(|i| i.count > 0);
+    }
+}
+
+impl From<event::Cargo> for Cargo {
+    fn from(value: event::Cargo) -> Self {
+        let mut items: Vec<CargoItem> = value.inventory
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| CargoItem {
+                name: i.name_localised.unwrap_or_else(|| title_case(&i.name).into()),
+                commodity: i.name.to_lowercase().into(),
+                count: i.count,
+                stolen: i.stolen,
+                mission_id: i.mission_id,
+            })
+            .collect();
+
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        Cargo { items }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stolen_goods_are_removed_only_when_sold_as_stolen_or_last() {
+        let mut cargo = Cargo::default();
+        cargo.add("Gold", None, 4, false, None);
+        cargo.add("gold", None, 2, true, None);
+        assert_eq!(cargo.items.len(), 1);
+        assert_eq!((cargo.items[0].count, cargo.items[0].stolen), (6, 2));
+        assert_eq!(cargo.items[0].name.as_ref(), "Gold");
+
+        cargo.remove("gold", 1, true, None);
+        assert_eq!((cargo.items[0].count, cargo.items[0].stolen), (5, 1));
+
+        cargo.remove("gold", 3, false, None);
+        assert_eq!((cargo.items[0].count, cargo.items[0].stolen), (2, 1));
+
+        cargo.remove("gold", 2, false, None);
+        assert!(cargo.items.is_empty());
+    }
+
+    #[test]
+    fn mission_cargo_is_kept_apart() {
+        let mut cargo = Cargo::default();
+        cargo.add("Tea", Some("Tea".into()), 5, false, None);
+        cargo.add("Tea", Some("Tea".into()), 3, false, Some(42));
+        assert_eq!(cargo.used(), 8);
+
+        cargo.remove("tea", 3, false, Some(42));
+        assert_eq!(cargo.items.len(), 1);
+        assert_eq!(cargo.items[0].mission_id, None);
+        assert_eq!(cargo.items[0].count, 5);
+
+        cargo.remove("tea", 2, false, Some(7));
+        assert_eq!(cargo.used(), 5);
+    }
+}
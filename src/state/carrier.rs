@@ -0,0 +1,231 @@
+use crate::journal::event;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Weekly upkeep of the carrier core, paid regardless of which services are installed.
+const CORE_UPKEEP: u64 = 5_000_000;
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Carrier {
+    pub carrier_id: u64,
+    pub name: Box<str>,
+    pub callsign: Box<str>,
+    pub system_name: Box<str>,
+    pub system_address: u64,
+    pub body_name: Option<Box<str>>,
+    pub fuel_level: u64,
+    pub tritium_reserve: u64,
+    pub jump_range: f64,
+    pub docking_access: Option<Box<str>>,
+    pub finance: Finance,
+    pub pending_jump: Option<PendingJump>,
+    pub pending_decommission: Option<DateTime<Utc>>,
+    pub crew_services: Vec<CrewService>,
+    pub trade_orders: Vec<TradeOrder>,
+    pub materials: Vec<Material>,
+    pub space_total: u64,
+    pub space_free: u64,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Finance {
+    pub balance: u64,
+    pub reserve: u64,
+    pub available: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingJump {
+    pub system_name: Box<str>,
+    pub system_address: u64,
+    pub body_name: Option<Box<str>>,
+    pub departure_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CrewService {
+    pub role: Box<str>,
+    pub crew_name: Option<Box<str>>,
+    pub active: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TradeOrder {
+    pub commodity: Box<str>,
+    pub name: Box<str>,
+    pub kind: OrderKind,
+    pub quantity: u64,
+    pub price: u64,
+    pub black_market: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum OrderKind {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Material {
+    pub name: Box<str>,
+    pub count: u64,
+}
+
+impl PendingJump {
+
+    /// Time left until the carrier leaves, or `None` once the departure time has passed.
+    pub fn countdown(&self) -> Option<chrono::Duration> {
+        self.departure_time
+            .map(|t| t - Utc::now())
+            .filter(|d| d.num_seconds() > 0)
+    }
+}
+
+impl CrewService {
+
+    /// Weekly upkeep of an individual crew service, as charged while it is active.
+    pub fn upkeep(&self) -> u64 {
+        if !self.active {
+            return 0;
+        }
+
+        match self.role.as_ref() {
+            "Refuel" | "Repair" | "Rearm" => 1_500_000,
+            "Shipyard" => 6_500_000,
+            "Outfitting" => 5_000_000,
+            "Exploration" | "VoucherRedemption" => 1_850_000,
+            "BlackMarket" => 2_000_000,
+            "Bartender" => 1_750_000,
+            "PioneerSupplies" => 5_000_000,
+            _ => 0,
+        }
+    }
+}
+
+impl Carrier {
+
+    /// Estimated weekly upkeep based on the core and every active crew service.
+    pub fn upkeep(&self) -> u64 {
+        CORE_UPKEEP + self.crew_services.iter().map(|s| s.upkeep()).sum::<u64>()
+    }
+
+    pub fn update_from_stats(&mut self, value: event::CarrierStats) {
+        self.carrier_id = value.carrier_id;
+        self.name = value.name;
+        self.callsign = value.callsign;
+        self.fuel_level = value.fuel_level;
+        self.jump_range = value.jump_range_curr;
+        self.docking_access = Some(value.docking_access);
+        self.space_total = value.space_usage.total_capacity;
+        self.space_free = value.space_usage.free_space;
+        self.finance = Finance {
+            balance: value.finance.carrier_balance,
+            reserve: value.finance.reserve_balance,
+            available: value.finance.available_balance,
+        };
+        self.crew_services = value.crew
+            .into_iter()
+            .map(|c| CrewService {
+                role: c.crew_role,
+                crew_name: c.crew_name,
+                active: c.activated && c.enabled.unwrap_or(true),
+            })
+            .collect();
+
+        if !value.pending_decommission {
+            self.pending_decommission = None;
+        }
+    }
+
+    pub fn update_from_finance(&mut self, value: event::CarrierFinance) {
+        self.finance = Finance {
+            balance: value.carrier_balance,
+            reserve: value.reserve_balance,
+            available: value.available_balance,
+        };
+    }
+
+    pub fn update_crew_service(&mut self, value: event::CarrierCrewServices) {
+        // deactivated services are uninstalled entirely, paused ones stay on board
+        let active = match value.operation.as_ref() {
+            "Activate" | "Resume" | "Replace" => true,
+            "Pause" => false,
+            "Deactivate" => {
+                self.crew_services.retain(|s| s.role != value.crew_role);
+                return;
+            }
+            _ => return,
+        };
+
+        match self.crew_services.iter_mut().find(|s| s.role == value.crew_role) {
+            Some(service) => {
+                service.active = active;
+                service.crew_name = Some(value.crew_name);
+            }
+            None => self.crew_services.push(CrewService {
+                role: value.crew_role,
+                crew_name: Some(value.crew_name),
+                active,
+            }),
+        }
+    }
+
+    pub fn update_trade_order(&mut self, value: event::CarrierTradeOrder) {
+        // cancelled or replaced orders drop the existing one for this commodity
+        self.trade_orders.retain(|o| o.commodity != value.commodity);
+
+        if value.cancel_trade.unwrap_or_default() {
+            return;
+        }
+
+        let (kind, quantity) = match (value.purchase_order, value.sale_order) {
+            (Some(quantity), _) => (OrderKind::Buy, quantity),
+            (None, Some(quantity)) => (OrderKind::Sell, quantity),
+            (None, None) => return,
+        };
+
+        self.trade_orders.push(TradeOrder {
+            name: value.commodity_localised.unwrap_or(value.commodity.clone()),
+            commodity: value.commodity,
+            kind,
+            quantity,
+            price: value.price.unwrap_or_default(),
+            black_market: value.black_market,
+        });
+        self.trade_orders.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    pub fn complete_jump(&mut self, system_name: Box<str>, system_address: u64, body_name: Option<Box<str>>) {
+        self.system_name = system_name;
+        self.system_address = system_address;
+        self.body_name = body_name;
+        self.pending_jump = None;
+    }
+}
+
+impl From<event::CarrierJumpRequest> for PendingJump {
+    fn from(value: event::CarrierJumpRequest) -> Self {
+        PendingJump {
+            system_name: value.system_name,
+            system_address: value.system_address,
+            body_name: value.body,
+            departure_time: value.departure_time,
+        }
+    }
+}
+
+impl From<event::FCMaterials> for Vec<Material> {
+    fn from(value: event::FCMaterials) -> Self {
+        let mut materials: Vec<Material> = value.items
+            .unwrap_or_default()
+            .into_iter()
+            .map(|m| Material {
+                name: m.name_localised.unwrap_or(m.name),
+                count: m.stock,
+            })
+            .collect();
+
+        materials.sort_by(|a, b| a.name.cmp(&b.name));
+        materials
+    }
+}
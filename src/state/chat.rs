@@ -3,7 +3,9 @@ use crate::journal::event;
 use crate::journal::format::prettify_date;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct Message {
     pub timestamp: i64,
     pub time_display: Box<str>,
@@ -19,6 +21,7 @@ pub enum Kind {
     Npc
 }
 
+#[derive(Serialize, Deserialize)]
 pub enum Channel {
     Local,
     Npc,
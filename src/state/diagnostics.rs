@@ -0,0 +1,65 @@
+use crate::journal::UnparsedLine;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Most recent unparsed lines kept for display; older ones only count towards the total
+const MAX_UNPARSED: usize = 50;
+
+/// Journal lines we couldn't make sense of this session, so schema drift and new events show up
+/// somewhere other than the log.
+#[derive(Default, Clone, Debug)]
+pub struct Diagnostics {
+    pub unparsed_count: u64,
+    pub unparsed: VecDeque<UnparsedLine>,
+    /// Events with no schema, by name, with how many times each was seen
+    pub unknown_events: BTreeMap<Box<str>, u64>,
+}
+
+impl Diagnostics {
+    pub fn unparsed(&mut self, line: UnparsedLine) {
+        self.unparsed_count += 1;
+        if self.unparsed.len() == MAX_UNPARSED {
+            self.unparsed.pop_front();
+        }
+        self.unparsed.push_back(line);
+    }
+
+    pub fn unknown_event(&mut self, name: &str) {
+        *self.unknown_events.entry(name.into()).or_default() += 1;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unparsed_count == 0 && self.unknown_events.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(number: usize) -> UnparsedLine {
+        UnparsedLine { file: "Journal.log".into(), error: "missing field `StarSystem`".into(), line: number.to_string().into() }
+    }
+
+    #[test]
+    fn only_the_latest_unparsed_lines_are_kept() {
+        let mut diagnostics = Diagnostics::default();
+        for number in 0..MAX_UNPARSED + 5 {
+            diagnostics.unparsed(line(number));
+        }
+
+        assert_eq!(diagnostics.unparsed_count, MAX_UNPARSED as u64 + 5);
+        assert_eq!(diagnostics.unparsed.len(), MAX_UNPARSED);
+        assert_eq!(diagnostics.unparsed.front().unwrap().line.as_ref(), "5");
+    }
+
+    #[test]
+    fn unknown_events_are_counted_by_name() {
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.unknown_event("ColonisationSomethingNew");
+        diagnostics.unknown_event("ColonisationSomethingNew");
+        diagnostics.unknown_event("Other");
+
+        assert_eq!(diagnostics.unknown_events["ColonisationSomethingNew"], 2);
+        assert!(!diagnostics.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::journal::event;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Engineer {
     pub engineer: Box<str>,
     pub engineer_id: u64,
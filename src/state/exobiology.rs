@@ -0,0 +1,253 @@
+use crate::journal::event;
+use crate::lookup::{COLONY_DISTANCES, EXOBIOLOGY_VALUES};
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+const FIRST_FOOTFALL_MULTIPLIER: u64 = 5;
+
+/// Organic scanning progress per body (keyed by system address, then body id), plus analysed
+/// samples still waiting to be sold.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Exobiology {
+    pub bodies: HashMap<u64, HashMap<u8, Vec<Species>>>,
+    pub unsold: Vec<Sample>,
+    pub position: Option<Position>,
+    pub planet_radius: Option<f64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Stage {
+    Seen,
+    Logged,
+    Sampled,
+    Analysed,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Species {
+    pub genus: Box<str>,
+    pub name: Box<str>,
+    pub variant: Option<Box<str>>,
+    pub stage: Stage,
+    pub samples: Vec<Position>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Sample {
+    pub name: Box<str>,
+    pub system_address: u64,
+    pub body_id: u8,
+    pub value: u64,
+    pub first_footfall: bool,
+}
+
+impl Stage {
+    pub fn samples_taken(&self) -> u8 {
+        match self {
+            Stage::Seen => 0,
+            Stage::Logged => 1,
+            Stage::Sampled => 2,
+            Stage::Analysed => 3,
+        }
+    }
+}
+
+impl Position {
+    /// Great-circle distance in metres to another point on a body with the given radius.
+    pub fn distance_to(&self, other: &Position, radius: f64) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * radius * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    /// Initial great-circle bearing in degrees (0..360, clockwise from north) to another point.
+    pub fn bearing_to(&self, other: &Position) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let y = d_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
+
+impl Species {
+
+    /// How far apart samples of this genus must be taken, in metres.
+    pub fn colony_distance(&self) -> Option<u32> {
+        COLONY_DISTANCES.get(self.genus.as_ref()).copied()
+    }
+
+    /// The base Vista Genomics payout for this species, if known.
+    pub fn value(&self) -> Option<u64> {
+        EXOBIOLOGY_VALUES.get(self.name.as_ref()).copied()
+    }
+
+    /// Distance in metres from the given position to the closest sample already taken.
+    pub fn nearest_sample(&self, position: &Position, radius: f64) -> Option<f64> {
+        self.samples
+            .iter()
+            .map(|s| s.distance_to(position, radius))
+            .min_by(|a, b| a.total_cmp(b))
+    }
+
+    /// Whether the given position is far enough from every previous sample to take another.
+    pub fn can_sample_at(&self, position: &Position, radius: f64) -> bool {
+        match (self.nearest_sample(position, radius), self.colony_distance()) {
+            (Some(nearest), Some(required)) => nearest >= required as f64,
+            _ => true,
+        }
+    }
+}
+
+impl Exobiology {
+
+    pub fn species(&self, system_address: u64, body_id: u8) -> &[Species] {
+        self.bodies
+            .get(&system_address)
+            .and_then(|bodies| bodies.get(&body_id))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The number of species fully analysed on a body.
+    pub fn analysed_count(&self, system_address: u64, body_id: u8) -> usize {
+        self.species(system_address, body_id)
+            .iter()
+            .filter(|s| s.stage == Stage::Analysed)
+            .count()
+    }
+
+    pub fn unsold_value(&self) -> u64 {
+        self.unsold.iter().map(|s| s.value).sum()
+    }
+
+    /// Records a species spotted with the composition scanner without sampling it. Codex entries
+    /// name the variant, e.g. "Stratum Tectonicas - Green", so the colour is split off to find the
+    /// species that ScanOrganic will report.
+    pub fn seen(&mut self, system_address: u64, body_id: u8, name: Box<str>) {
+        let species: Box<str> = name.split_once(" - ").map_or(name.as_ref(), |(species, _)| species).into();
+        let genus: Box<str> = species.split_whitespace().next().unwrap_or_default().into();
+        let variant = (species != name).then_some(name);
+        self.entry(system_address, body_id, genus, species, variant);
+    }
+
+    /// Applies a genetic sampler scan and returns the completed sample once analysed.
+    pub fn scan(&mut self, event: event::ScanOrganic, first_footfall: bool) -> Option<&Sample> {
+        let position = self.position;
+        let body_id = event.body as u8;
+        let genus = event.genus_localised.unwrap_or(event.genus);
+        let name = event.species_localised.unwrap_or(event.species);
+        let variant = event.variant_localised.or(event.variant);
+
+        let species = self.entry(event.system_address, body_id, genus.clone(), name, variant);
+        // the genus guessed from a codex entry might not be the one the sampler reports
+        species.genus = genus;
+        species.stage = match event.scan_type.as_ref() {
+            "Log" => Stage::Logged,
+            "Sample" => Stage::Sampled,
+            "Analyse" => Stage::Analysed,
+            _ => species.stage,
+        };
+
+        if species.stage != Stage::Analysed {
+            species.samples.extend(position);
+            return None;
+        }
+
+        let name = species.name.clone();
+        let base_value = species.value().unwrap_or_default();
+        species.samples.clear();
+
+        self.unsold.push(Sample {
+            name,
+            system_address: event.system_address,
+            body_id,
+            value: if first_footfall { base_value * FIRST_FOOTFALL_MULTIPLIER } else { base_value },
+            first_footfall,
+        });
+        self.unsold.last()
+    }
+
+    /// Removes sold samples from the unsold list, one per species in the sale.
+    pub fn sell(&mut self, sold: &[Box<str>]) {
+        for name in sold {
+            if let Some(pos) = self.unsold.iter().position(|s| &s.name == name) {
+                self.unsold.remove(pos);
+            }
+        }
+    }
+
+    /// Samples in progress or unsold are lost when the commander dies.
+    pub fn lose_samples(&mut self) {
+        self.unsold.clear();
+        for species in self.bodies.values_mut().flat_map(|b| b.values_mut()).flatten() {
+            if species.stage != Stage::Analysed {
+                species.stage = Stage::Seen;
+                species.samples.clear();
+            }
+        }
+    }
+
+    fn entry(&mut self, system_address: u64, body_id: u8, genus: Box<str>, name: Box<str>, variant: Option<Box<str>>) -> &mut Species {
+        let species = self.bodies
+            .entry(system_address)
+            .or_default()
+            .entry(body_id)
+            .or_default();
+
+        let index = match species.iter().position(|s| s.name == name) {
+            Some(index) => index,
+            None => {
+                species.push(Species { genus, name, variant: None, stage: Stage::Seen, samples: Vec::new() });
+                species.len() - 1
+            }
+        };
+
+        let entry = &mut species[index];
+        if variant.is_some() {
+            entry.variant = variant;
+        }
+        entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_is_measured_along_the_surface() {
+        let a = Position { latitude: 0.0, longitude: 0.0 };
+        let b = Position { latitude: 0.0, longitude: 90.0 };
+        let radius = 1_000_000.0;
+
+        let distance = a.distance_to(&b, radius);
+        assert!((distance - radius * std::f64::consts::FRAC_PI_2).abs() < 0.01);
+    }
+
+    #[test]
+    fn samples_must_be_a_colony_distance_apart() {
+        let species = Species {
+            genus: "Bacterium".into(),
+            name: "Bacterium Cerbrus".into(),
+            variant: None,
+            stage: Stage::Logged,
+            samples: vec![Position { latitude: 0.0, longitude: 0.0 }],
+        };
+        let radius = 1_000_000.0;
+
+        // one degree of latitude on a 1000km body is about 17.4km; a tenth of a millidegree is ~1.7m
+        assert!(!species.can_sample_at(&Position { latitude: 0.0001, longitude: 0.0 }, radius));
+        assert!(species.can_sample_at(&Position { latitude: 0.1, longitude: 0.0 }, radius));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::state::fss;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+const PLANET_MASS_FACTOR: f64 = 0.56591828;
+const FIRST_DISCOVERY_MULTIPLIER: f64 = 2.6;
+const MINIMUM_BODY_VALUE: f64 = 500.0;
+
+/// Cartographic data gathered since it was last sold, keyed by system address.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Exploration {
+    pub systems: HashMap<u64, UnsoldSystem>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct UnsoldSystem {
+    pub name: Box<str>,
+    pub bodies: HashMap<u8, u64>,
+}
+
+impl UnsoldSystem {
+    pub fn value(&self) -> u64 {
+        self.bodies.values().sum()
+    }
+}
+
+impl Exploration {
+
+    pub fn unsold_value(&self) -> u64 {
+        self.systems.values().map(UnsoldSystem::value).sum()
+    }
+
+    pub fn system_value(&self, system_address: u64) -> u64 {
+        self.systems.get(&system_address).map(UnsoldSystem::value).unwrap_or_default()
+    }
+
+    /// Records (or re-values, e.g. after mapping) a scanned body.
+    pub fn record(&mut self, system_address: u64, system_name: &str, body: &fss::Body) {
+        let system = self.systems.entry(system_address).or_default();
+        if system.name.is_empty() {
+            system.name = system_name.into();
+        }
+        system.bodies.insert(body.id, body_value(body));
+    }
+
+    /// Forgets data for the named systems once it has been sold.
+    pub fn sell<'a>(&mut self, system_names: impl IntoIterator<Item = &'a str>) {
+        for name in system_names {
+            self.systems.retain(|_, s| !s.name.eq_ignore_ascii_case(name));
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.systems.clear();
+    }
+}
+
+/// Estimates what the game pays for a body's cartographic data, based on its class and mass,
+/// whether we are first to discover or map it, and whether it was mapped efficiently.
+pub fn body_value(body: &fss::Body) -> u64 {
+    if body.is_star {
+        return star_value(body).round() as u64;
+    }
+
+    let class = body.r#type.as_deref().unwrap_or_default().to_lowercase();
+    let (base, terraform_bonus) = planet_constants(&class);
+    let k = if body.terraformable || body.is_earthlike { base + terraform_bonus } else { base };
+
+    let first_discovery = !body.was_discovered;
+    let first_mapped = !body.was_mapped;
+
+    let mapping_multiplier = match (body.mapped, first_discovery, first_mapped) {
+        (false, _, _) => 1.0,
+        (true, true, true) => 3.699622554,
+        (true, false, true) => 8.0956,
+        (true, _, false) => 10.0 / 3.0,
+    };
+
+    let mut value = (k + k * PLANET_MASS_FACTOR * body.mass.powf(0.2)) * mapping_multiplier;
+
+    if body.mapped {
+        value += (value * 0.3).max(555.0);
+        if body.mapped_efficiently {
+            value *= 1.25;
+        }
+    }
+
+    value = value.max(MINIMUM_BODY_VALUE);
+    if first_discovery {
+        value *= FIRST_DISCOVERY_MULTIPLIER;
+    }
+
+    value.round() as u64
+}
+
+fn star_value(body: &fss::Body) -> f64 {
+    let k = match body.star_type.as_deref().unwrap_or_default() {
+        "SupermassiveBlackHole" => 33.5678,
+        "N" | "H" => 22628.0,
+        t if t.starts_with('D') => 14057.0,
+        _ => 1200.0,
+    };
+
+    let value = k + body.mass * k / 66.25;
+    if body.was_discovered { value } else { value * FIRST_DISCOVERY_MULTIPLIER }
+}
+
+fn planet_constants(class: &str) -> (f64, f64) {
+    if class.contains("metal rich") { (21790.0, 0.0) }
+    else if class.contains("ammonia world") { (96932.0, 0.0) }
+    else if class.contains("class i gas giant") { (1656.0, 0.0) }
+    else if class.contains("high metal content") || class.contains("class ii gas giant") { (9654.0, 100677.0) }
+    else if class.contains("earthlike") || class.contains("water world") { (64831.0, 116295.0) }
+    else { (300.0, 93328.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn planet(class: &str, mass: f64) -> fss::Body {
+        fss::Body {
+            r#type: Some(class.into()),
+            mass,
+            was_discovered: true,
+            was_mapped: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn earthlike_worlds_are_worth_more_than_rocks() {
+        let earthlike = body_value(&fss::Body { is_earthlike: true, ..planet("Earthlike body", 1.0) });
+        let rock = body_value(&planet("Rocky body", 1.0));
+
+        assert_eq!(rock, 500);
+        assert!(earthlike > 250_000);
+    }
+
+    #[test]
+    fn first_discovery_and_mapping_increase_value() {
+        let known = planet("Water world", 0.5);
+        let discovered = fss::Body { was_discovered: false, was_mapped: false, ..known.clone() };
+        let mapped = fss::Body { mapped: true, mapped_efficiently: true, ..discovered.clone() };
+
+        assert!(body_value(&discovered) > body_value(&known));
+        assert!(body_value(&mapped) > body_value(&discovered) * 3);
+    }
+
+    #[test]
+    fn selling_clears_named_systems() {
+        let mut exploration = Exploration::default();
+        exploration.record(1, "Sol", &planet("Rocky body", 1.0));
+        exploration.record(2, "Achenar", &planet("Rocky body", 1.0));
+        exploration.sell(["sol"]);
+
+        assert_eq!(exploration.unsold_value(), 500);
+        assert_eq!(exploration.system_value(1), 0);
+    }
+}
@@ -2,15 +2,16 @@ use crate::image::{planet, station};
 use crate::journal::event;
 use crate::{edsm, image, BoxStrOptionExt};
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Fss {
     pub progress: Option<ScanProgress>,
     pub bodies: HashMap<u8, Body>,
     pub signals: Vec<Signal>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct ScanProgress {
     pub progress: u8,
     pub body_count: u8,
@@ -27,7 +28,7 @@ impl From<event::FSSDiscoveryScan> for ScanProgress {
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Body {
     pub id: u8,
     pub name: Box<str>,
@@ -39,6 +40,8 @@ pub struct Body {
     pub discovery: Option<BodyDiscovery>,
     pub was_mapped: bool,
     pub was_footfalled: bool,
+    pub mapped: bool,
+    pub mapped_efficiently: bool,
     pub atmosphere: Option<Box<str>>,
     pub atmosphere_type: Option<Box<str>>,
     pub volcanism: Option<Box<str>>,
@@ -50,12 +53,14 @@ pub struct Body {
     pub is_gas_giant: bool,
     pub is_earthlike: bool,
     pub is_star: bool,
+    pub star_type: Option<Box<str>>,
+    pub mass: f64,
     pub has_life: bool,
     pub distance_ls: f64,
     pub is_journal_scan: bool,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct BodyDiscovery {
     pub commander: Box<str>,
     pub date: Box<str>,
@@ -131,6 +136,7 @@ impl Body {
         self.id = event.body_id as u8;
         self.was_discovered = event.was_discovered;
         self.was_mapped = event.was_mapped;
+        self.was_footfalled = event.was_footfalled.unwrap_or_default();
         self.terraformable = event.terraform_state.as_deref() == Some("Terraformable");
         self.atmosphere_type = event.atmosphere_type;
         self.volcanism = event.volcanism;
@@ -144,6 +150,8 @@ impl Body {
         self.is_high_metal_content = event.planet_class.as_ref().is_some_and(|pc| pc.as_ref() == "High metal content body");
         self.is_gas_giant = event.planet_class.as_ref().is_some_and(|pc| pc.as_ref().to_lowercase().contains("gas giant"));
         self.is_star = event.star_type.is_some();
+        self.star_type = event.star_type.clone();
+        self.mass = event.mass_em.or(event.stellar_mass).unwrap_or_default();
 
         if let Some(rings) = event.rings {
             self.rings = rings.into_iter().map(|r| r.name).collect();
@@ -213,7 +221,7 @@ impl Body {
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct SignalCount {
     pub kind: Box<str>,
     pub count: u32,
@@ -237,12 +245,16 @@ impl From<event::Scan> for Body {
             is_landable: value.landable.unwrap_or_default(),
             rings: value.rings.unwrap_or_default().into_iter().map(|ring| ring.name).collect(),
             was_footfalled: value.was_footfalled.unwrap_or_default(),
+            mapped: false,
+            mapped_efficiently: false,
             is_ammonia_world: value.atmosphere_type.is_some_and(|atm| atm.as_ref() == "Ammonia"),
             is_water_world: value.planet_class.as_ref().is_some_and(|pc| pc.as_ref() == "Water world"),
             is_earthlike: value.planet_class.as_ref().is_some_and(|pc| pc.as_ref() == "Earthlike body"),
             is_high_metal_content: value.planet_class.as_ref().is_some_and(|pc| pc.as_ref() == "High metal content body"),
             is_gas_giant: value.planet_class.as_ref().is_some_and(|pc| pc.as_ref().to_lowercase().contains("gas giant")),
             is_star: value.star_type.is_some(),
+            mass: value.mass_em.or(value.stellar_mass).unwrap_or_default(),
+            star_type: value.star_type,
             r#type: value.planet_class.filter(|s| !s.is_empty()),
             has_life: false,
             distance_ls: value.distance_from_arrival_ls,
@@ -271,7 +283,7 @@ impl From<event::FSSBodySignals> for Body {
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Signal {
     pub name: Box<str>,
     pub kind: Option<Box<str>>,
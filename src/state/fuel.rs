@@ -0,0 +1,173 @@
+use crate::state::navigation::NavRouteStep;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Jumps kept for working out how much fuel a light year costs
+const MAX_JUMPS: usize = 10;
+
+/// FuelScoop events further apart than this belong to different scooping runs
+const SCOOP_GAP_SECONDS: f64 = 60.0;
+
+/// Fuel in the tanks, what recent jumps cost and how fast we last scooped. Status.json keeps the
+/// levels current, the journal events fill in between and supply the history.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Fuel {
+    /// Tons in the main tank and the reservoir that feeds the power plant
+    pub main: Option<f64>,
+    pub reservoir: Option<f64>,
+    pub jumps: VecDeque<Jump>,
+    /// Tons per second while scooping, from the last two FuelScoop events of a run
+    pub scoop_rate: Option<f64>,
+    last_scoop: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Jump {
+    pub distance: f64,
+    pub fuel_used: f64,
+}
+
+impl Fuel {
+
+    pub fn observe(&mut self, main: f64, reservoir: f64) {
+        self.main = Some(main);
+        self.reservoir = Some(reservoir);
+    }
+
+    pub fn jumped(&mut self, distance: f64, fuel_used: f64, fuel_level: f64) {
+        self.main = Some(fuel_level);
+        if distance > 0.0 && fuel_used > 0.0 {
+            if self.jumps.len() == MAX_JUMPS {
+                self.jumps.pop_front();
+            }
+            self.jumps.push_back(Jump { distance, fuel_used });
+        }
+    }
+
+    pub fn scooped(&mut self, scooped: f64, total: f64, timestamp: DateTime<Utc>) {
+        self.main = Some(total);
+
+        if let Some(last) = self.last_scoop {
+            let seconds = (timestamp - last).num_milliseconds() as f64 / 1000.0;
+            if seconds > 0.0 && seconds <= SCOOP_GAP_SECONDS {
+                self.scoop_rate = Some(scooped / seconds);
+            }
+        }
+        self.last_scoop = Some(timestamp);
+    }
+
+    /// Bought fuel goes into the main tank, which can't hold more than `capacity`.
+    pub fn refuelled(&mut self, amount: f64, capacity: f64) {
+        let main = self.main.unwrap_or_default() + amount;
+        self.main = Some(if capacity > 0.0 { main.min(capacity) } else { main });
+    }
+
+    pub fn fuel_per_jump(&self) -> Option<f64> {
+        if self.jumps.is_empty() {
+            return None;
+        }
+        Some(self.jumps.iter().map(|jump| jump.fuel_used).sum::<f64>() / self.jumps.len() as f64)
+    }
+
+    /// Average fuel burned per light year over the recent jumps.
+    pub fn fuel_per_ly(&self) -> Option<f64> {
+        let distance: f64 = self.jumps.iter().map(|jump| jump.distance).sum();
+        if distance <= 0.0 {
+            return None;
+        }
+        Some(self.jumps.iter().map(|jump| jump.fuel_used).sum::<f64>() / distance)
+    }
+
+    /// The index of the first step in `route` we couldn't reach, if the tank runs dry on the way.
+    /// Scoopable stars are assumed to fill the tank back up to `capacity` after arriving.
+    ///
+    /// This is an estimate: each jump is costed at the recent average per light year, whereas
+    /// the FSD's real cost rises faster than linearly with distance and falls as the tank (and
+    /// so the ship) gets lighter. Routes of long jumps may run dry a step or two sooner.
+    pub fn runs_dry_at(&self, route: &[NavRouteStep], start: &[f64], capacity: f64) -> Option<usize> {
+        let mut fuel = self.main?;
+        let per_ly = self.fuel_per_ly()?;
+        let mut position = start;
+
+        // the route starts with the system we're in, which doesn't refuel us by itself
+        let ahead = route.iter().enumerate().skip_while(|(_, step)| step.star_pos == start);
+        for (i, step) in ahead {
+            let needed = distance(position, &step.star_pos) * per_ly;
+            if needed > fuel {
+                return Some(i);
+            }
+            fuel -= needed;
+            if step.is_fuel_star() && capacity > 0.0 {
+                fuel = capacity;
+            }
+            position = &step.star_pos;
+        }
+        None
+    }
+}
+
+fn distance(from: &[f64], to: &[f64]) -> f64 {
+    if from.len() < 3 || to.len() < 3 {
+        return 0.0;
+    }
+    from.iter().zip(to).map(|(a, b)| (a - b) * (a - b)).sum::<f64>().sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn step(name: &str, x: f64, star_class: &str) -> NavRouteStep {
+        NavRouteStep {
+            system_address: 0,
+            star_system: name.into(),
+            star_pos: vec![x, 0.0, 0.0],
+            star_class: star_class.into(),
+        }
+    }
+
+    #[test]
+    fn jumps_and_scooping_are_tracked() {
+        let mut fuel = Fuel::default();
+        fuel.jumped(20.0, 2.0, 14.0);
+        fuel.jumped(30.0, 4.0, 10.0);
+        assert_eq!(fuel.main, Some(10.0));
+        assert_eq!(fuel.fuel_per_jump(), Some(3.0));
+        assert_eq!(fuel.fuel_per_ly(), Some(0.12));
+
+        let at = |seconds| Utc.with_ymd_and_hms(3311, 1, 1, 12, 0, seconds).unwrap();
+        fuel.scooped(5.0, 15.0, at(0));
+        assert_eq!(fuel.scoop_rate, None);
+        fuel.scooped(5.0, 20.0, at(10));
+        assert_eq!(fuel.scoop_rate, Some(0.5));
+
+        fuel.refuelled(20.0, 32.0);
+        assert_eq!(fuel.main, Some(32.0));
+    }
+
+    #[test]
+    fn route_runs_dry_past_unscoopable_stars() {
+        let mut fuel = Fuel::default();
+        fuel.jumped(10.0, 1.0, 3.0);
+
+        // 10 ly a jump at 0.1 T/ly, with 3 T in the tank
+        let route = vec![
+            step("Here", 0.0, "K"),
+            step("A", 10.0, "L"),
+            step("B", 20.0, "TTS"),
+            step("C", 30.0, "Y"),
+            step("D", 40.0, "DA"),
+        ];
+        assert_eq!(fuel.runs_dry_at(&route, &[0.0, 0.0, 0.0], 16.0), Some(4));
+
+        let route = vec![
+            step("A", 10.0, "L"),
+            step("B", 20.0, "K"),
+            step("C", 30.0, "Y"),
+            step("D", 40.0, "DA"),
+        ];
+        assert_eq!(fuel.runs_dry_at(&route, &[0.0, 0.0, 0.0], 16.0), None);
+    }
+}
@@ -2,7 +2,9 @@ use log::warn;
 use crate::journal::event;
 use crate::journal::format::prettify_date;
 use thousands::Separable;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct EventLog {
     pub timestamp: i64,
     pub time_display: Box<str>,
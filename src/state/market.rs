@@ -1,16 +1,19 @@
 use crate::journal::event;
 use crate::journal::format::title_case;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Market {
     pub groups: Vec<ItemGroup>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct ItemGroup {
     pub name: Box<str>,
     pub items: Vec<Item>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct Item {
     pub name: Box<str>,
     pub buy_price: u64,
@@ -1,21 +1,22 @@
 use crate::journal::event;
 use crate::lookup::fdev_ids::all_materials;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct Materials {
     pub raw: Vec<Group>,
     pub manufactured: Vec<Group>,
     pub encoded: Vec<Group>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Group {
     pub name: Box<str>,
     pub materials: Vec<Material>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Material {
     pub id: Box<str>,
     pub name: Box<str>,
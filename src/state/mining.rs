@@ -0,0 +1,234 @@
+use crate::journal::event;
+use crate::journal::format::title_case;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+
+/// Mining activity, split into sessions that end when we leave the ring or the game.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Mining {
+    pub current: Option<Session>,
+    pub history: Vec<Session>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Session {
+    pub location: Box<str>,
+    pub started: DateTime<Utc>,
+    pub latest: DateTime<Utc>,
+    pub prospected: Vec<Prospect>,
+    pub asteroids_cracked: u32,
+    pub prospector_limpets: u32,
+    pub collector_limpets: u32,
+    pub fragments_collected: u64,
+    pub refined: BTreeMap<Box<str>, u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Prospect {
+    pub content: Box<str>,
+    pub materials: Vec<ProspectedMaterial>,
+    pub motherlode: Option<Box<str>>,
+    pub remaining: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProspectedMaterial {
+    pub name: Box<str>,
+    pub percentage: f64,
+}
+
+/// How often a material showed up in prospected asteroids, and how rich those asteroids were.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialSummary {
+    pub name: Box<str>,
+    pub asteroids: u32,
+    pub average_percentage: f64,
+    pub best_percentage: f64,
+}
+
+impl Session {
+
+    fn new(location: Box<str>, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            location,
+            started: timestamp,
+            latest: timestamp,
+            prospected: Vec::new(),
+            asteroids_cracked: 0,
+            prospector_limpets: 0,
+            collector_limpets: 0,
+            fragments_collected: 0,
+            refined: BTreeMap::new(),
+        }
+    }
+
+    pub fn tons_refined(&self) -> u64 {
+        self.refined.values().sum()
+    }
+
+    pub fn tons_per_hour(&self) -> Option<f64> {
+        let seconds = (self.latest - self.started).num_seconds();
+        if seconds <= 0 {
+            return None;
+        }
+        Some(self.tons_refined() as f64 * 3600.0 / seconds as f64)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.prospected.is_empty()
+            && self.asteroids_cracked == 0
+            && self.prospector_limpets == 0
+            && self.collector_limpets == 0
+            && self.fragments_collected == 0
+            && self.refined.is_empty()
+    }
+
+    pub fn motherlodes(&self) -> usize {
+        self.prospected.iter().filter(|p| p.motherlode.is_some()).count()
+    }
+
+    pub fn limpets_used(&self) -> u32 {
+        self.prospector_limpets + self.collector_limpets
+    }
+
+    pub fn material_summary(&self) -> Vec<MaterialSummary> {
+        let mut summary: Vec<MaterialSummary> = Vec::new();
+
+        for material in self.prospected.iter().flat_map(|p| &p.materials) {
+            match summary.iter_mut().find(|s| s.name == material.name) {
+                Some(s) => {
+                    s.average_percentage = (s.average_percentage * s.asteroids as f64 + material.percentage)
+                        / (s.asteroids + 1) as f64;
+                    s.best_percentage = s.best_percentage.max(material.percentage);
+                    s.asteroids += 1;
+                }
+                None => summary.push(MaterialSummary {
+                    name: material.name.clone(),
+                    asteroids: 1,
+                    average_percentage: material.percentage,
+                    best_percentage: material.percentage,
+                }),
+            }
+        }
+
+        summary.sort_by(|a, b| b.asteroids.cmp(&a.asteroids).then(a.name.cmp(&b.name)));
+        summary
+    }
+}
+
+impl Mining {
+
+    /// Closes the current session, keeping it in the history if anything happened during it.
+    pub fn end_session(&mut self) {
+        if let Some(session) = self.current.take().filter(|session| !session.is_empty()) {
+            self.history.push(session);
+        }
+    }
+
+    pub fn prospected(&mut self, event: event::ProspectedAsteroid, location: &str) {
+        let session = self.session(location, event.timestamp);
+        session.prospected.push(Prospect {
+            content: event.content_localised.unwrap_or(event.content),
+            materials: event.materials
+                .into_iter()
+                .map(|m| ProspectedMaterial {
+                    name: m.name_localised.unwrap_or_else(|| title_case(&m.name).into()),
+                    percentage: m.proportion,
+                })
+                .collect(),
+            motherlode: event.motherlode_material_localised.or(event.motherlode_material),
+            remaining: event.remaining,
+        });
+    }
+
+    pub fn cracked(&mut self, location: &str, timestamp: DateTime<Utc>) {
+        self.session(location, timestamp).asteroids_cracked += 1;
+    }
+
+    pub fn refined(&mut self, name: Box<str>, location: &str, timestamp: DateTime<Utc>) {
+        *self.session(location, timestamp).refined.entry(name).or_default() += 1;
+    }
+
+    pub fn launched(&mut self, kind: &str, location: &str, timestamp: DateTime<Utc>) {
+        // repair, fuel, hatch breaker and other limpets have nothing to do with mining
+        match kind {
+            "Prospector" => self.session(location, timestamp).prospector_limpets += 1,
+            "Collection" => self.session(location, timestamp).collector_limpets += 1,
+            _ => {}
+        }
+    }
+
+    /// Only counts collected cargo while a session is running, so scooping up canisters elsewhere is ignored.
+    pub fn collected(&mut self, timestamp: DateTime<Utc>) {
+        if let Some(session) = &mut self.current {
+            session.fragments_collected += 1;
+            session.latest = timestamp;
+        }
+    }
+
+    fn session(&mut self, location: &str, timestamp: DateTime<Utc>) -> &mut Session {
+        let session = self.current.get_or_insert_with(|| Session::new(location.into(), timestamp));
+        session.latest = timestamp;
+        session
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(3311, 1, 1, 12, 0, 0).unwrap() + chrono::Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn refined_tons_are_counted_per_hour() {
+        let mut mining = Mining::default();
+        mining.launched("Prospector", "Ring A", at(0));
+        for minute in 1..=15 {
+            mining.refined("Platinum".into(), "Ring A", at(minute * 2));
+        }
+
+        let session = mining.current.as_ref().unwrap();
+        assert_eq!(session.tons_refined(), 15);
+        assert_eq!(session.tons_per_hour(), Some(30.0));
+        assert_eq!(session.limpets_used(), 1);
+
+        mining.end_session();
+        assert!(mining.current.is_none());
+        assert_eq!(mining.history.len(), 1);
+    }
+
+    #[test]
+    fn material_summary_averages_prospects() {
+        let prospect = |percentage| Prospect {
+            content: "High".into(),
+            materials: vec![ProspectedMaterial { name: "Platinum".into(), percentage }],
+            motherlode: None,
+            remaining: 100.0,
+        };
+
+        let mut session = Session::new("Ring A".into(), at(0));
+        session.prospected = vec![prospect(20.0), prospect(40.0)];
+
+        assert_eq!(session.material_summary(), vec![MaterialSummary {
+            name: "Platinum".into(),
+            asteroids: 2,
+            average_percentage: 30.0,
+            best_percentage: 40.0,
+        }]);
+    }
+
+    #[test]
+    fn other_limpets_dont_start_a_session() {
+        let mut mining = Mining::default();
+        mining.launched("Repair", "Ring A", at(0));
+        mining.launched("FuelTransfer", "Ring A", at(1));
+        assert!(mining.current.is_none());
+
+        mining.launched("Collection", "Ring A", at(2));
+        assert_eq!(mining.current.as_ref().unwrap().collector_limpets, 1);
+    }
+}
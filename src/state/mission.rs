@@ -1,6 +1,8 @@
 use crate::journal::event;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct Mission {
 
     pub faction: Box<str>,
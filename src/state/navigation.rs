@@ -1,7 +1,8 @@
 use crate::ardent;
 use crate::journal::event;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct NavRouteStep {
 
     pub system_address: u64,
@@ -10,7 +11,7 @@ pub struct NavRouteStep {
     pub star_class: Box<str>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct CurrentLocation {
     pub docked: bool,
     pub station_name: Option<Box<str>>,
@@ -49,13 +50,13 @@ pub struct CurrentLocation {
     pub deaths: Option<Counts>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct StationEconomy {
     pub name: Box<str>,
     pub proportion: f64,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Faction {
     pub name: Box<str>,
     pub faction_state: Box<str>,
@@ -68,18 +69,18 @@ pub struct Faction {
     pub active_states: Vec<FactionState>
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct SystemFaction {
     pub name: Box<str>,
     pub faction_state: Option<Box<str>>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct FactionState {
     pub state: Box<str>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Station {
     pub id: i64,
     pub market_id: i64,
@@ -99,7 +100,7 @@ pub struct Station {
     pub update_time: LastUpdated,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct StationBody {
     pub id: i64,
     pub name: Box<str>,
@@ -107,7 +108,7 @@ pub struct StationBody {
     pub longitude: Option<f32>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct LastUpdated {
     pub information: Box<str>,
     pub market: Option<Box<str>>,
@@ -115,13 +116,13 @@ pub struct LastUpdated {
     pub outfitting: Option<Box<str>>,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct System {
     pub address: u64,
     pub name: Box<str>
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Counts {
     pub day: u64,
     pub week: u64,
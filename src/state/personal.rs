@@ -1,6 +1,7 @@
 use crate::journal::event;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Rank {
     pub combat: u8,
     pub trade: u8,
@@ -12,14 +13,14 @@ pub struct Rank {
     pub cqc: u8
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct CrimeStats {
     pub legal_state: Box<str>,
     pub active_fine: bool,
     pub wanted: bool,
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Reputation {
     pub empire: f64,
     pub federation: f64,
@@ -1,4 +1,6 @@
-#[derive(Default, Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Powerplay {
     pub power: Option<Box<str>>,      // Current pledged power (if any)
     pub rank: Option<u8>,             // Current powerplay rank (0-5/10 depending on schema)
@@ -0,0 +1,174 @@
+use chrono::{DateTime, Duration, Utc};
+use iced::task;
+use std::collections::HashMap;
+
+/// Data that's shown this long after it was fetched is flagged as possibly out of date.
+const STALE_AFTER: Duration = Duration::minutes(30);
+
+/// The EDSM and Ardent queries made for a system, one per kind of data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Source {
+    Bodies,
+    Stations,
+    Traffic,
+    Deaths,
+    NearbySystems,
+}
+
+impl Source {
+    pub const ALL: &'static [Source] = &[
+        Source::Bodies,
+        Source::Stations,
+        Source::Traffic,
+        Source::Deaths,
+        Source::NearbySystems,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Source::Bodies => "bodies",
+            Source::Stations => "stations",
+            Source::Traffic => "traffic",
+            Source::Deaths => "deaths",
+            Source::NearbySystems => "nearby systems",
+        }
+    }
+}
+
+/// How the queries for the current system are getting on, so panes can tell "still loading" and
+/// "couldn't load" apart from "nothing there".
+#[derive(Default, Clone, Debug)]
+pub struct Queries {
+    pub system_address: u64,
+    pub system_name: Box<str>,
+    sources: HashMap<Source, SourceStatus>,
+    /// Requests still running for this system, aborted once we've moved on
+    in_flight: Vec<task::Handle>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct SourceStatus {
+    pub in_flight: bool,
+    pub succeeded_at: Option<DateTime<Utc>>,
+    pub failure: Option<Failure>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Failure {
+    pub at: DateTime<Utc>,
+    pub reason: Box<str>,
+}
+
+/// The combined status of the sources a pane is built from, most pressing first.
+#[derive(Debug, PartialEq)]
+pub enum Summary<'a> {
+    Loading,
+    Failed(Vec<(Source, &'a str)>),
+    Stale(DateTime<Utc>),
+    Current,
+}
+
+impl Queries {
+
+    /// Marks the sources as in flight. Starting on a different system forgets the old one and
+    /// cancels whatever was still being fetched for it.
+    pub fn started(&mut self, system_address: u64, system_name: &str, sources: &[Source], handle: Option<task::Handle>) {
+        if self.system_address != system_address {
+            for handle in self.in_flight.drain(..) {
+                handle.abort();
+            }
+            self.system_address = system_address;
+            self.system_name = system_name.into();
+            self.sources.clear();
+        }
+        for source in sources {
+            self.sources.entry(*source).or_default().in_flight = true;
+        }
+        self.in_flight.retain(|handle| !handle.is_aborted());
+        self.in_flight.extend(handle);
+    }
+
+    pub fn succeeded(&mut self, source: Source, at: DateTime<Utc>) {
+        let status = self.sources.entry(source).or_default();
+        status.in_flight = false;
+        status.succeeded_at = Some(at);
+        status.failure = None;
+    }
+
+    pub fn failed(&mut self, source: Source, reason: Box<str>, at: DateTime<Utc>) {
+        let status = self.sources.entry(source).or_default();
+        status.in_flight = false;
+        status.failure = Some(Failure { at, reason });
+    }
+
+    pub fn get(&self, source: Source) -> Option<&SourceStatus> {
+        self.sources.get(&source)
+    }
+
+    /// The sources that failed on their latest attempt, for retrying.
+    pub fn failed_sources(&self) -> Vec<Source> {
+        Source::ALL.iter()
+            .copied()
+            .filter(|s| self.get(*s).is_some_and(|status| status.failure.is_some()))
+            .collect()
+    }
+
+    pub fn summary(&self, sources: &[Source], now: DateTime<Utc>) -> Summary<'_> {
+        let statuses: Vec<(Source, &SourceStatus)> = sources.iter()
+            .filter_map(|s| self.get(*s).map(|status| (*s, status)))
+            .collect();
+
+        if statuses.iter().any(|(_, status)| status.in_flight) {
+            return Summary::Loading;
+        }
+
+        let failures: Vec<(Source, &str)> = statuses.iter()
+            .filter_map(|(source, status)| status.failure.as_ref().map(|f| (*source, f.reason.as_ref())))
+            .collect();
+        if !failures.is_empty() {
+            return Summary::Failed(failures);
+        }
+
+        match statuses.iter().filter_map(|(_, status)| status.succeeded_at).min() {
+            Some(oldest) if now - oldest > STALE_AFTER => Summary::Stale(oldest),
+            _ => Summary::Current,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PANE: &[Source] = &[Source::Stations, Source::Traffic];
+
+    #[test]
+    fn summary_prefers_loading_then_failures_then_staleness() {
+        let now = Utc::now();
+        let mut queries = Queries::default();
+        assert_eq!(queries.summary(PANE, now), Summary::Current);
+
+        queries.started(10477373803, "Sol", Source::ALL, None);
+        assert_eq!(queries.summary(PANE, now), Summary::Loading);
+
+        queries.succeeded(Source::Stations, now - Duration::hours(2));
+        queries.failed(Source::Traffic, "503 Service Unavailable".into(), now);
+        assert_eq!(queries.summary(PANE, now), Summary::Failed(vec![(Source::Traffic, "503 Service Unavailable")]));
+        assert_eq!(queries.failed_sources(), vec![Source::Traffic]);
+
+        queries.started(10477373803, "Sol", &[Source::Traffic], None);
+        queries.succeeded(Source::Traffic, now);
+        assert_eq!(queries.summary(PANE, now), Summary::Stale(now - Duration::hours(2)));
+    }
+
+    #[test]
+    fn a_new_system_forgets_the_old_one() {
+        let mut queries = Queries::default();
+        queries.started(10477373803, "Sol", Source::ALL, None);
+        queries.failed(Source::Bodies, "timed out".into(), Utc::now());
+
+        queries.started(164098653, "Achenar", &[Source::Stations], None);
+        assert!(queries.get(Source::Bodies).is_none());
+        assert!(queries.get(Source::Stations).unwrap().in_flight);
+    }
+}
@@ -3,7 +3,7 @@ use regex::Regex;
 use crate::journal::event;
 use crate::lookup::fdev_ids::Outfitting;
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Loadout {
 
     pub ship_type: Box<str>,
@@ -23,6 +23,7 @@ pub struct Loadout {
     pub optional_internals: Vec<Module>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct Module {
 
     pub slot: SlotType,
@@ -39,13 +40,14 @@ pub struct Module {
     pub mount: Box<str>,
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct FuelCapacity {
 
     pub main: f64,
     pub reserve: f64,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct Engineering {
 
     pub engineer: Box<str>,
@@ -56,6 +58,7 @@ pub struct Engineering {
     pub modifiers: Vec<Modifier>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct Modifier {
 
     pub label: Box<str>,
@@ -131,7 +134,7 @@ impl From<event::LoadoutFuelCapacity> for FuelCapacity {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Locker {
 
     pub items: Vec<ShipLockerItem>,
@@ -140,7 +143,7 @@ pub struct Locker {
     pub data: Vec<ShipLockerItem>
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct ShipLockerItem {
 
     pub name: Box<str>,
@@ -149,6 +152,7 @@ pub struct ShipLockerItem {
     pub locations: Vec<Box<str>>
 }
 
+#[derive(Serialize, Deserialize)]
 pub enum SlotType {
     Hardpoints { size: u8 },
     CoreInternal,
@@ -238,6 +242,7 @@ impl From<&str> for SlotType {
 use crate::lookup::fdev_ids::Shipyard;
 use crate::lookup;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
 impl From<event::Inventory> for Locker {
     fn from(value: event::Inventory) -> Self {
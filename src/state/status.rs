@@ -0,0 +1,267 @@
+use crate::journal::event;
+
+/// What Status.json last said about the ship or the commander on foot. It's rewritten every time
+/// anything changes, so this is only ever the latest copy and isn't kept in the checkpoint.
+#[derive(Default, Clone, Debug)]
+pub struct Status {
+    pub flags: Flags,
+    pub flags2: Flags2,
+    pub pips: Option<Pips>,
+    pub fire_group: Option<u8>,
+    pub gui_focus: GuiFocus,
+    /// Tons in the main tank and the reservoir that feeds the power plant
+    pub fuel_main: Option<f64>,
+    pub fuel_reservoir: Option<f64>,
+    pub cargo: Option<f64>,
+    pub heading: Option<f64>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Metres, measured from the surface or the planet's average radius, see `Flag::AltitudeFromAverageRadius`
+    pub altitude: Option<f64>,
+    pub planet_radius: Option<f64>,
+    pub body_name: Option<Box<str>>,
+    /// On foot: oxygen and health as 0..1, temperature in kelvin and gravity in g
+    pub oxygen: Option<f64>,
+    pub health: Option<f64>,
+    pub temperature: Option<f64>,
+    pub gravity: Option<f64>,
+    pub selected_weapon: Option<Box<str>>,
+    pub destination: Option<Destination>,
+}
+
+/// Power distributor settings, in half pips (0..=8) as the game reports them.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Pips {
+    pub systems: u8,
+    pub engines: u8,
+    pub weapons: u8,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Destination {
+    pub system: u64,
+    pub name: Box<str>,
+}
+
+/// The bits of Status.json's `Flags`. All of them are listed, not just the ones shown.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flag {
+    Docked = 0,
+    Landed = 1,
+    LandingGearDown = 2,
+    ShieldsUp = 3,
+    Supercruise = 4,
+    FlightAssistOff = 5,
+    HardpointsDeployed = 6,
+    InWing = 7,
+    LightsOn = 8,
+    CargoScoopDeployed = 9,
+    SilentRunning = 10,
+    ScoopingFuel = 11,
+    SrvHandbrake = 12,
+    SrvTurretView = 13,
+    SrvTurretRetracted = 14,
+    SrvDriveAssist = 15,
+    FsdMassLocked = 16,
+    FsdCharging = 17,
+    FsdCooldown = 18,
+    LowFuel = 19,
+    OverHeating = 20,
+    HasLatLong = 21,
+    IsInDanger = 22,
+    BeingInterdicted = 23,
+    InMainShip = 24,
+    InFighter = 25,
+    InSrv = 26,
+    HudInAnalysisMode = 27,
+    NightVision = 28,
+    AltitudeFromAverageRadius = 29,
+    FsdJump = 30,
+    SrvHighBeam = 31,
+}
+
+/// The bits of Status.json's `Flags2`, mostly about being on foot.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flag2 {
+    OnFoot = 0,
+    InTaxi = 1,
+    InMulticrew = 2,
+    OnFootInStation = 3,
+    OnFootOnPlanet = 4,
+    AimDownSight = 5,
+    LowOxygen = 6,
+    LowHealth = 7,
+    Cold = 8,
+    Hot = 9,
+    VeryCold = 10,
+    VeryHot = 11,
+    GlideMode = 12,
+    OnFootInHangar = 13,
+    OnFootSocialSpace = 14,
+    OnFootExterior = 15,
+    BreathableAtmosphere = 16,
+    TelepresenceMulticrew = 17,
+    PhysicalMulticrew = 18,
+    FsdHyperdriveCharging = 19,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Flags(pub u32);
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Flags2(pub u32);
+
+impl Flags {
+    pub fn has(&self, flag: Flag) -> bool {
+        self.0 & (1 << flag as u32) != 0
+    }
+}
+
+impl Flags2 {
+    pub fn has(&self, flag: Flag2) -> bool {
+        self.0 & (1 << flag as u32) != 0
+    }
+}
+
+/// Which screen or panel has the commander's attention.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuiFocus {
+    #[default]
+    None,
+    InternalPanel,
+    ExternalPanel,
+    CommsPanel,
+    RolePanel,
+    StationServices,
+    GalaxyMap,
+    SystemMap,
+    Orrery,
+    Fss,
+    Saa,
+    Codex,
+    Other(u8),
+}
+
+impl From<u8> for GuiFocus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => GuiFocus::None,
+            1 => GuiFocus::InternalPanel,
+            2 => GuiFocus::ExternalPanel,
+            3 => GuiFocus::CommsPanel,
+            4 => GuiFocus::RolePanel,
+            5 => GuiFocus::StationServices,
+            6 => GuiFocus::GalaxyMap,
+            7 => GuiFocus::SystemMap,
+            8 => GuiFocus::Orrery,
+            9 => GuiFocus::Fss,
+            10 => GuiFocus::Saa,
+            11 => GuiFocus::Codex,
+            other => GuiFocus::Other(other),
+        }
+    }
+}
+
+impl GuiFocus {
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            GuiFocus::None | GuiFocus::Other(_) => None,
+            GuiFocus::InternalPanel => Some("Internal Panel"),
+            GuiFocus::ExternalPanel => Some("External Panel"),
+            GuiFocus::CommsPanel => Some("Comms Panel"),
+            GuiFocus::RolePanel => Some("Role Panel"),
+            GuiFocus::StationServices => Some("Station Services"),
+            GuiFocus::GalaxyMap => Some("Galaxy Map"),
+            GuiFocus::SystemMap => Some("System Map"),
+            GuiFocus::Orrery => Some("Orrery"),
+            GuiFocus::Fss => Some("FSS"),
+            GuiFocus::Saa => Some("Surface Scanner"),
+            GuiFocus::Codex => Some("Codex"),
+        }
+    }
+}
+
+impl Status {
+    pub fn on_foot(&self) -> bool {
+        self.flags2.has(Flag2::OnFoot)
+    }
+
+    pub fn in_srv(&self) -> bool {
+        self.flags.has(Flag::InSrv)
+    }
+
+    /// Whether the game is running at all; Status.json is left with no flags when it isn't.
+    pub fn is_live(&self) -> bool {
+        self.flags.0 != 0 || self.flags2.0 != 0
+    }
+}
+
+impl From<&event::Status> for Status {
+    fn from(value: &event::Status) -> Self {
+        Status {
+            flags: Flags(value.flags.unwrap_or_default() as u32),
+            flags2: Flags2(value.flags2.unwrap_or_default() as u32),
+            pips: value.pips.as_ref().and_then(|pips| match pips.as_slice() {
+                [systems, engines, weapons] => Some(Pips {
+                    systems: *systems as u8,
+                    engines: *engines as u8,
+                    weapons: *weapons as u8,
+                }),
+                _ => None,
+            }),
+            fire_group: value.fire_group.map(|group| group as u8),
+            gui_focus: value.gui_focus.map(|focus| GuiFocus::from(focus as u8)).unwrap_or_default(),
+            fuel_main: value.fuel.as_ref().map(|fuel| fuel.fuel_main),
+            fuel_reservoir: value.fuel.as_ref().map(|fuel| fuel.fuel_reservoir),
+            cargo: value.cargo,
+            heading: value.heading.map(|heading| heading as f64),
+            latitude: value.latitude,
+            longitude: value.longitude,
+            altitude: value.altitude.map(|altitude| altitude as f64),
+            planet_radius: value.planet_radius,
+            body_name: value.body_name.clone(),
+            oxygen: value.oxygen,
+            health: value.health,
+            temperature: value.temperature,
+            gravity: value.gravity,
+            selected_weapon: value.selected_weapon.clone(),
+            destination: value.destination.as_ref().map(|destination| Destination {
+                system: destination.system,
+                name: destination.name.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_are_read_by_bit() {
+        // docked, landing gear down, shields up, in main ship, has lat/long
+        let flags = Flags(0b1_0010_0000_0000_0000_0000_1101);
+        assert!(flags.has(Flag::Docked));
+        assert!(flags.has(Flag::LandingGearDown));
+        assert!(flags.has(Flag::ShieldsUp));
+        assert!(flags.has(Flag::HasLatLong));
+        assert!(flags.has(Flag::InMainShip));
+        assert!(!flags.has(Flag::Landed));
+        assert!(!flags.has(Flag::HardpointsDeployed));
+
+        let flags2 = Flags2(0b1_0000_0000_0001_0001);
+        assert!(flags2.has(Flag2::OnFoot));
+        assert!(flags2.has(Flag2::OnFootOnPlanet));
+        assert!(flags2.has(Flag2::BreathableAtmosphere));
+        assert!(!flags2.has(Flag2::InTaxi));
+    }
+
+    #[test]
+    fn gui_focus_keeps_unknown_values() {
+        assert_eq!(GuiFocus::from(6), GuiFocus::GalaxyMap);
+        assert_eq!(GuiFocus::from(42), GuiFocus::Other(42));
+        assert_eq!(GuiFocus::from(0).label(), None);
+    }
+}
@@ -2,22 +2,23 @@ use log::error;
 use crate::journal::event;
 use crate::lookup;
 use crate::lookup::SuitClass;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Loadout {
-    pub suit_name: &'static str,
+    pub suit_name: Box<str>,
     pub class: u8,
-    pub suit_mods: Vec<&'static str>,
+    pub suit_mods: Vec<Box<str>>,
     pub loadout_name: Box<str>,
     pub modules: Vec<Module>,
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Module {
     pub slot_name: Box<str>,
     pub module_name: Box<str>,
     pub class: u64,
-    pub weapon_mods: Vec<&'static str>,
+    pub weapon_mods: Vec<Box<str>>,
 }
 
 impl From<event::SuitLoadoutModule> for Module {
@@ -27,11 +28,11 @@ impl From<event::SuitLoadoutModule> for Module {
             module_name: value.module_name_localised.unwrap_or(value.module_name),
             class: value.class,
             weapon_mods: value.weapon_mods.into_iter().map(|m| {
-                *lookup::SUIT_MODULE_NAMES.get(m.as_ref())
+                (*lookup::SUIT_MODULE_NAMES.get(m.as_ref())
                     .unwrap_or_else(|| {
                         error!("Error: Missing suit module name: {}", m);
                         &"Unknown"
-                    })
+                    })).into()
             }).collect(),
         }
     }
@@ -47,14 +48,14 @@ impl From<event::SuitLoadout> for Loadout {
             });
 
         Loadout {
-            suit_name: suit_class.name,
+            suit_name: suit_class.name.into(),
             class: suit_class.rank,
             suit_mods: value.suit_mods.into_iter().map(|m| {
-                *lookup::SUIT_MODULE_NAMES.get(m.as_ref())
+                (*lookup::SUIT_MODULE_NAMES.get(m.as_ref())
                     .unwrap_or_else(|| {
                         error!("Error: Missing suit module name: {}", m);
                         &"Unknown"
-                    })
+                    })).into()
             }).collect(),
             loadout_name: value.loadout_name,
             modules: value.modules.into_iter().map(|m| m.into()).collect(),
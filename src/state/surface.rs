@@ -0,0 +1,186 @@
+use crate::state::exobiology::Position;
+use crate::state::navigation::Station;
+use crate::state::status::Status;
+use serde::{Deserialize, Serialize};
+
+/// Settlements and bookmarks remembered, oldest dropped first
+const MAX_REMEMBERED: usize = 20;
+
+/// Places on planet surfaces we can navigate to, and the one currently chosen.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Surface {
+    #[serde(skip)]
+    pub target: Option<Target>,
+    pub settlements: Vec<Target>,
+    pub bookmarks: Vec<Target>,
+    pub last_sample: Option<Target>,
+    /// Bookmarks made so far, to number the next one
+    bookmarks_made: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TargetKind {
+    Station,
+    Settlement,
+    Bookmark,
+    Sample,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Target {
+    pub kind: TargetKind,
+    pub name: Box<str>,
+    pub body: Box<str>,
+    pub position: Position,
+}
+
+/// Where a target is from the current position: bearing in degrees and distance in metres.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Heading {
+    pub bearing: f64,
+    pub distance: f64,
+}
+
+impl TargetKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TargetKind::Station => "Station",
+            TargetKind::Settlement => "Settlement",
+            TargetKind::Bookmark => "Bookmark",
+            TargetKind::Sample => "Organic Sample",
+        }
+    }
+}
+
+impl Heading {
+    /// Degrees to turn from `heading` to face the target; negative is to the left.
+    pub fn turn_from(&self, heading: f64) -> f64 {
+        (self.bearing - heading + 540.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+impl Surface {
+
+    pub fn settlement(&mut self, name: Box<str>, body: Box<str>, position: Position) {
+        self.settlements.retain(|s| s.name != name || s.body != body);
+        remember(&mut self.settlements, Target { kind: TargetKind::Settlement, name, body, position });
+    }
+
+    pub fn sampled(&mut self, name: Box<str>, body: Box<str>, position: Position) {
+        self.last_sample = Some(Target { kind: TargetKind::Sample, name, body, position });
+    }
+
+    /// Bookmarks where Status.json says we are, if we're near a surface.
+    pub fn bookmark(&mut self, status: &Status) {
+        let (Some(body), Some(position)) = (&status.body_name, position(status)) else { return };
+        self.bookmarks_made += 1;
+        let name = format!("Bookmark {}", self.bookmarks_made).into();
+        remember(&mut self.bookmarks, Target { kind: TargetKind::Bookmark, name, body: body.clone(), position });
+    }
+
+    /// Everything on `body` we could navigate to, including stations with known coordinates.
+    pub fn targets(&self, body: &str, stations: &[Station]) -> Vec<Target> {
+        let stations = stations.iter().filter_map(|station| {
+            let station_body = station.body.as_ref()?;
+            Some(Target {
+                kind: TargetKind::Station,
+                name: station.name.clone(),
+                body: station_body.name.clone(),
+                position: Position {
+                    latitude: station_body.latitude? as f64,
+                    longitude: station_body.longitude? as f64,
+                },
+            })
+        });
+
+        stations
+            .chain(self.settlements.iter().cloned())
+            .chain(self.bookmarks.iter().cloned())
+            .chain(self.last_sample.iter().cloned())
+            .filter(|target| target.body.as_ref() == body)
+            .collect()
+    }
+
+    /// Bearing and distance to `target` from where Status.json says we are, if we're on its body.
+    pub fn heading_to(target: &Target, status: &Status) -> Option<Heading> {
+        if status.body_name.as_deref() != Some(target.body.as_ref()) {
+            return None;
+        }
+        let from = position(status)?;
+        let radius = status.planet_radius?;
+        Some(Heading {
+            bearing: from.bearing_to(&target.position),
+            distance: from.distance_to(&target.position, radius),
+        })
+    }
+}
+
+fn position(status: &Status) -> Option<Position> {
+    Some(Position { latitude: status.latitude?, longitude: status.longitude? })
+}
+
+fn remember(targets: &mut Vec<Target>, target: Target) {
+    if targets.len() == MAX_REMEMBERED {
+        targets.remove(0);
+    }
+    targets.push(target);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_at(latitude: f64, longitude: f64) -> Status {
+        Status {
+            latitude: Some(latitude),
+            longitude: Some(longitude),
+            planet_radius: Some(1_000_000.0),
+            body_name: Some("Sol 3".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn bearing_and_distance_follow_the_great_circle() {
+        let mut surface = Surface::default();
+        surface.settlement("Outpost".into(), "Sol 3".into(), Position { latitude: 0.0, longitude: 90.0 });
+        let target = &surface.targets("Sol 3", &[])[0];
+
+        let heading = Surface::heading_to(target, &status_at(0.0, 0.0)).unwrap();
+        assert!((heading.bearing - 90.0).abs() < 1e-9);
+        assert!((heading.distance - 1_000_000.0 * std::f64::consts::FRAC_PI_2).abs() < 0.01);
+        assert!((heading.turn_from(180.0) + 90.0).abs() < 1e-9);
+        assert!((heading.turn_from(350.0) - 100.0).abs() < 1e-9);
+
+        let north = Surface::heading_to(target, &status_at(-10.0, 90.0)).unwrap();
+        assert!(north.bearing.abs() < 1e-9);
+    }
+
+    #[test]
+    fn targets_are_limited_to_the_current_body() {
+        let mut surface = Surface::default();
+        surface.bookmark(&status_at(12.0, 34.0));
+        surface.sampled("Bacterium Cerbrus".into(), "Sol 4".into(), Position { latitude: 1.0, longitude: 2.0 });
+
+        let targets = surface.targets("Sol 3", &[]);
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].kind, TargetKind::Bookmark);
+        assert_eq!(targets[0].position, Position { latitude: 12.0, longitude: 34.0 });
+
+        let elsewhere = Status { body_name: Some("Sol 4".into()), ..status_at(1.0, 2.0) };
+        assert_eq!(Surface::heading_to(&targets[0], &elsewhere), None);
+    }
+
+    #[test]
+    fn bookmarks_keep_counting_past_the_oldest_being_dropped() {
+        let mut surface = Surface::default();
+        for _ in 0..MAX_REMEMBERED + 2 {
+            surface.bookmark(&status_at(12.0, 34.0));
+        }
+
+        let names: Vec<&str> = surface.bookmarks.iter().map(|b| b.name.as_ref()).collect();
+        assert_eq!(names.len(), MAX_REMEMBERED);
+        assert_eq!(names.first(), Some(&"Bookmark 3"));
+        assert_eq!(names.last(), Some(&"Bookmark 22"));
+    }
+}
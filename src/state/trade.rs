@@ -0,0 +1,263 @@
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use serde::{Deserialize, Serialize};
+
+/// Trade runs kept in the ledger, oldest dropped first
+const MAX_RUNS: usize = 100;
+
+/// Pairs commodity purchases with later sales, first in first out, to work out trading profit.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Ledger {
+    lots: HashMap<Box<str>, VecDeque<Lot>>,
+    run_started: Option<DateTime<Utc>>,
+    buying_since_last_sale: bool,
+    pub runs: Vec<Run>,
+    pub session: Totals,
+    pub lifetime: Totals,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Lot {
+    count: u64,
+    price: u64,
+}
+
+/// A single trade run: everything sold at one market after the last round of buying.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Run {
+    pub market_id: u64,
+    pub station_name: Option<Box<str>>,
+    pub started: DateTime<Utc>,
+    pub finished: DateTime<Utc>,
+    pub tons: u64,
+    pub cost: u64,
+    pub revenue: u64,
+}
+
+/// A commodity sale, as the ledger needs it from a MarketSell event.
+pub struct Sale<'a> {
+    pub commodity: &'a str,
+    pub count: u64,
+    pub price: u64,
+    pub avg_price_paid: u64,
+    pub market_id: u64,
+    pub station_name: Option<Box<str>>,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Totals {
+    pub started: Option<DateTime<Utc>>,
+    pub latest: Option<DateTime<Utc>>,
+    pub tons: u64,
+    pub cost: u64,
+    pub revenue: u64,
+}
+
+impl Run {
+    pub fn profit(&self) -> i64 {
+        self.revenue as i64 - self.cost as i64
+    }
+
+    pub fn profit_per_hour(&self) -> Option<f64> {
+        per_hour(self.profit(), self.started, self.finished)
+    }
+}
+
+impl Totals {
+    pub fn profit(&self) -> i64 {
+        self.revenue as i64 - self.cost as i64
+    }
+
+    pub fn profit_per_hour(&self) -> Option<f64> {
+        per_hour(self.profit(), self.started?, self.latest?)
+    }
+
+    fn record(&mut self, tons: u64, cost: u64, revenue: u64, timestamp: DateTime<Utc>) {
+        self.started.get_or_insert(timestamp);
+        self.latest = Some(timestamp);
+        self.tons += tons;
+        self.cost += cost;
+        self.revenue += revenue;
+    }
+}
+
+fn per_hour(profit: i64, started: DateTime<Utc>, finished: DateTime<Utc>) -> Option<f64> {
+    let seconds = (finished - started).num_seconds();
+    if seconds <= 0 {
+        return None;
+    }
+    Some(profit as f64 * 3600.0 / seconds as f64)
+}
+
+impl Ledger {
+
+    /// Starts a new game session; lifetime totals and open purchases carry over.
+    pub fn start_session(&mut self, timestamp: DateTime<Utc>) {
+        self.session = Totals {
+            started: Some(timestamp),
+            ..Default::default()
+        };
+    }
+
+    pub fn buy(&mut self, commodity: &str, count: u64, price: u64, timestamp: DateTime<Utc>) {
+        if !self.buying_since_last_sale {
+            self.run_started = Some(timestamp);
+            self.buying_since_last_sale = true;
+        }
+
+        self.lots
+            .entry(commodity.to_lowercase().into())
+            .or_default()
+            .push_back(Lot { count, price });
+    }
+
+    /// Records a sale, costing it against the oldest purchases first. Anything we have no purchase
+    /// for (bought before the journals begin, or mined) is costed at the average price the game reports.
+    pub fn sell(&mut self, sale: Sale) {
+        let Sale { commodity, count, price, avg_price_paid, market_id, station_name, timestamp } = sale;
+        let cost = self.take(commodity, count, Some(avg_price_paid));
+        let revenue = count * price;
+
+        self.session.record(count, cost, revenue, timestamp);
+        self.lifetime.record(count, cost, revenue, timestamp);
+
+        let continues_run = !self.buying_since_last_sale
+            && self.runs.last().is_some_and(|r| r.market_id == market_id);
+
+        if continues_run {
+            if let Some(run) = self.runs.last_mut() {
+                run.finished = timestamp;
+                run.tons += count;
+                run.cost += cost;
+                run.revenue += revenue;
+            }
+        } else {
+            if self.runs.len() == MAX_RUNS {
+                self.runs.remove(0);
+            }
+            self.runs.push(Run {
+                market_id,
+                station_name,
+                started: self.run_started.unwrap_or(timestamp),
+                finished: timestamp,
+                tons: count,
+                cost,
+                revenue,
+            });
+        }
+
+        self.buying_since_last_sale = false;
+    }
+
+    /// Drops purchased cargo that left the hold without being sold.
+    pub fn discard(&mut self, commodity: &str, count: u64) {
+        self.take(commodity, count, None);
+    }
+
+    /// Forgets every open purchase, e.g. when the ship is destroyed.
+    pub fn clear_holdings(&mut self) {
+        self.lots.clear();
+        self.buying_since_last_sale = false;
+    }
+
+    fn take(&mut self, commodity: &str, count: u64, fallback_price: Option<u64>) -> u64 {
+        let mut remaining = count;
+        let mut cost = 0;
+
+        if let Some(lots) = self.lots.get_mut(commodity.to_lowercase().as_str()) {
+            while remaining > 0 {
+                let Some(lot) = lots.front_mut() else { break };
+                let taken = remaining.min(lot.count);
+                cost += taken * lot.price;
+                lot.count -= taken;
+                remaining -= taken;
+
+                if lot.count == 0 {
+                    lots.pop_front();
+                }
+            }
+        }
+
+        cost + remaining * fallback_price.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(3311, 1, 1, 12, 0, 0).unwrap() + chrono::Duration::minutes(minutes)
+    }
+
+    fn sale(commodity: &str, count: u64, price: u64, avg_price_paid: u64, market_id: u64, timestamp: DateTime<Utc>) -> Sale<'_> {
+        Sale { commodity, count, price, avg_price_paid, market_id, station_name: None, timestamp }
+    }
+
+    #[test]
+    fn sales_are_costed_first_in_first_out() {
+        let mut ledger = Ledger::default();
+        ledger.buy("Gold", 10, 100, at(0));
+        ledger.buy("gold", 10, 200, at(1));
+        ledger.sell(sale("gold", 15, 300, 150, 1, at(30)));
+
+        let run = ledger.runs.last().unwrap();
+        assert_eq!(run.cost, 10 * 100 + 5 * 200);
+        assert_eq!(run.revenue, 15 * 300);
+        assert_eq!(ledger.lifetime.tons, 15);
+
+        ledger.sell(sale("gold", 5, 300, 150, 1, at(31)));
+        assert_eq!(ledger.runs.len(), 1);
+        assert_eq!(ledger.runs[0].cost, 10 * 100 + 10 * 200);
+    }
+
+    #[test]
+    fn unmatched_sales_use_the_average_price_paid() {
+        let mut ledger = Ledger::default();
+        ledger.sell(sale("silver", 4, 500, 120, 7, at(0)));
+
+        assert_eq!(ledger.runs[0].cost, 4 * 120);
+        assert_eq!(ledger.runs[0].profit(), 4 * 380);
+    }
+
+    #[test]
+    fn buying_again_starts_a_new_run() {
+        let mut ledger = Ledger::default();
+        ledger.buy("gold", 10, 100, at(0));
+        ledger.sell(sale("gold", 10, 200, 100, 1, at(30)));
+        ledger.buy("gold", 10, 100, at(40));
+        ledger.sell(sale("gold", 10, 250, 100, 1, at(70)));
+
+        assert_eq!(ledger.runs.len(), 2);
+        assert_eq!(ledger.runs[1].profit(), 1500);
+        assert_eq!(ledger.runs[1].profit_per_hour(), Some(3000.0));
+        assert_eq!(ledger.lifetime.profit(), 2500);
+    }
+
+    #[test]
+    fn new_session_keeps_lifetime_totals() {
+        let mut ledger = Ledger::default();
+        ledger.sell(sale("gold", 1, 100, 0, 1, at(0)));
+        ledger.start_session(at(10));
+        ledger.sell(sale("gold", 1, 100, 0, 1, at(70)));
+
+        assert_eq!(ledger.session.revenue, 100);
+        assert_eq!(ledger.session.profit_per_hour(), Some(100.0));
+        assert_eq!(ledger.lifetime.revenue, 200);
+    }
+
+    #[test]
+    fn only_recent_runs_are_kept() {
+        let mut ledger = Ledger::default();
+        for run in 0..MAX_RUNS as i64 + 5 {
+            ledger.buy("gold", 1, 100, at(run * 2));
+            ledger.sell(sale("gold", 1, 200, 100, 1, at(run * 2 + 1)));
+        }
+
+        assert_eq!(ledger.runs.len(), MAX_RUNS);
+        assert_eq!(ledger.runs[0].started, at(10));
+        assert_eq!(ledger.lifetime.tons, MAX_RUNS as u64 + 5);
+    }
+}
@@ -13,8 +13,18 @@ mod journal;
 pub fn subscription(state: &State) -> Subscription<Message> {
     if !state.journal_loaded {
         Subscription::batch(vec![
-            Subscription::run(journal::stream_history),
+            Subscription::run_with(state.journal_position.clone(), journal::stream_history),
             Subscription::run(stream_edsm_server_status),
+            Subscription::run(stream_edsm_upload_status),
+            Subscription::run(stream_api_state_requests),
+        ])
+    } else if crate::journal::replay::is_active() {
+        // the snapshot files belong to the live game, so only the replayed journal is streamed
+        Subscription::batch(vec![
+            Subscription::run(journal::stream_journal),
+            Subscription::run(hotkey::stream),
+            Subscription::run(stream_edsm_server_status),
+            Subscription::run(stream_api_state_requests),
         ])
     } else {
         Subscription::batch(vec![
@@ -25,8 +35,12 @@ pub fn subscription(state: &State) -> Subscription<Message> {
             Subscription::run(journal::stream_ship_locker),
             Subscription::run(journal::stream_market),
             Subscription::run(journal::stream_navroute),
+            Subscription::run(journal::stream_outfitting),
+            Subscription::run(journal::stream_shipyard),
             Subscription::run(hotkey::stream),
             Subscription::run(stream_edsm_server_status),
+            Subscription::run(stream_edsm_upload_status),
+            Subscription::run(stream_api_state_requests),
         ])
     }
 }
@@ -41,25 +55,59 @@ fn stream_edsm_server_status() -> impl iced::futures::Stream<Item = Message> {
 
     tokio::spawn(async move {
         use crate::edsm::EdsmClient;
-        use log::error;
+        use log::{info, warn};
+
+        const POLL: Duration = Duration::from_secs(30);
+        const MAX_POLL: Duration = Duration::from_secs(10 * 60);
+
         let client = EdsmClient::default();
-        let mut interval = tokio::time::interval(Duration::from_secs(30));
+        let mut failures = 0u32;
         loop {
-            interval.tick().await;
             match client.get_elite_server_status().await {
                 Ok(status) => {
+                    if failures > 0 {
+                        info!("EDSM status poll recovered after {} failures", failures);
+                    }
+                    failures = 0;
                     if sender.send(Message::Query(Query::EdsmServerStatus(status))).await.is_err() { break; }
                 }
                 Err(e) => {
-                    error!("EDSM status poll failed: {}", e);
+                    // the client has already retried, so EDSM is probably down; poll it less often
+                    failures += 1;
+                    warn!("EDSM status poll failed ({} in a row): {}", failures, e);
                 }
             }
+            let wait = POLL.saturating_mul(2u32.saturating_pow(failures)).min(MAX_POLL);
+            tokio::time::sleep(wait).await;
         }
     });
 
     ReceiverStream::new(receiver)
 }
 
+/// Forwards the EDSM journal upload status to the settings screen; ends straight away if the
+/// uploader isn't running.
+#[cfg(not(feature = "mock_events"))]
+fn stream_edsm_upload_status() -> impl iced::futures::Stream<Item = Message> {
+    iced::futures::stream::unfold(crate::edsm_upload::status(), |receiver| async move {
+        let mut receiver = receiver?;
+        receiver.changed().await.ok()?;
+        let status = receiver.borrow_and_update().clone();
+        Some((Message::Query(Query::EdsmUploadStatus(status)), Some(receiver)))
+    })
+}
+
+/// Asks for the API's state snapshot to be rebuilt whenever a client wants it; ends straight away
+/// if the API isn't running.
+#[cfg(not(feature = "mock_events"))]
+pub(crate) fn stream_api_state_requests() -> impl iced::futures::Stream<Item = Message> {
+    iced::futures::stream::unfold(crate::api::state_requests(), |receiver| async move {
+        let mut receiver = receiver?;
+        receiver.changed().await.ok()?;
+        Some((Message::ApiStateRequested, Some(receiver)))
+    })
+}
+
 #[cfg(feature = "mock_events")]
 mod example_data;
 
@@ -2,9 +2,11 @@ use iced::futures::Stream;
 use log::error;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
+use crate::journal::checkpoint;
 use crate::message::Message;
 
-pub fn stream_history() -> impl Stream<Item=Message> {
+pub fn stream_history(resume_from: &Option<checkpoint::Position>) -> impl Stream<Item=Message> {
+    let resume_from = resume_from.clone();
     let (sender, receiver) = mpsc::channel(64);
 
     tokio::spawn(async move {
@@ -40,13 +42,17 @@ pub fn stream_history() -> impl Stream<Item=Message> {
                 continue;
             }
 
-            // We have logs: load and emit messages, then exit the task
-            let loader = HistoryLoader::with_dir(dir);
-            match loader.load_messages() {
+            // We have logs: stream messages as they are read, then exit the task
+            let loader = HistoryLoader::with_dir(dir).resume_from(resume_from.clone());
+            match loader.messages() {
                 Ok(messages) => {
-                    for msg in messages.into_iter() {
-                        if sender.send(msg).await.is_err() { break; }
-                    }
+                    // reading waits on the parser threads, so feed the channel from a blocking thread
+                    let sender = sender.clone();
+                    let _ = tokio::task::spawn_blocking(move || {
+                        for msg in messages {
+                            if sender.blocking_send(msg).is_err() { break; }
+                        }
+                    }).await;
                     break;
                 }
                 Err(e) => {
@@ -65,7 +71,15 @@ pub fn stream_journal() -> impl Stream<Item=Message> {
     let (sender, receiver) = mpsc::channel(16);
 
     tokio::spawn(async move {
-        use crate::journal::JournalWatcher;
+        use crate::journal::{replay, JournalWatcher};
+
+        if replay::is_active() {
+            if let Err(e) = replay::play(sender).await {
+                error!("Journal replay failed: {}", e);
+            }
+            return;
+        }
+
         let mut watcher = match JournalWatcher::new() {
             Ok(w) => w,
             Err(e) => { error!("Failed to start journal watcher: {}", e); return; }
@@ -113,4 +127,8 @@ pub fn stream_ship_locker() -> impl Stream<Item=Message> { stream_snapshot("Ship
 
 pub fn stream_market() -> impl Stream<Item=Message> { stream_snapshot("Market.json") }
 
-pub fn stream_navroute() -> impl Stream<Item=Message> { stream_snapshot("NavRoute.json") }
\ No newline at end of file
+pub fn stream_navroute() -> impl Stream<Item=Message> { stream_snapshot("NavRoute.json") }
+
+pub fn stream_outfitting() -> impl Stream<Item=Message> { stream_snapshot("Outfitting.json") }
+
+pub fn stream_shipyard() -> impl Stream<Item=Message> { stream_snapshot("Shipyard.json") }
\ No newline at end of file
@@ -8,7 +8,7 @@ fn test_deserialize_file(path: &PathBuf) -> Result<(), String> {
     let content = fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
 
-    serde_json::from_str::<Vec<journal::Event>>(&content).map_err(|e| {
+    let events = serde_json::from_str::<Vec<journal::Event>>(&content).map_err(|e| {
         let error = format!("Failed to deserialize {}: {}", path.display(), e);
         let re = Regex::new(r"(.*?), expected one of.*?(at line \d+ column \d+)").unwrap();
         if let Some(captures) = re.captures(&error) {
@@ -17,6 +17,11 @@ fn test_deserialize_file(path: &PathBuf) -> Result<(), String> {
             error
         }
     })?;
+
+    // the fallback would hide events we're missing a schema for, so those still fail here
+    if let Some(journal::Event::Unknown { event, .. }) = events.iter().find(|e| matches!(e, journal::Event::Unknown { .. })) {
+        return Err(format!("Failed to deserialize {}: unknown variant `{}`", path.display(), event));
+    }
     Ok(())
 }
 